@@ -0,0 +1,4 @@
+// Migrations are embedded at compile time, so editing one must trigger a rebuild
+fn main() {
+    println!("cargo:rerun-if-changed=src/infrastructure/database/migrations");
+}
@@ -0,0 +1,136 @@
+// Floods the mission search with concurrent requests and checks that a route which never touches
+// the database keeps answering quickly. If repository calls blocked Tokio workers, the probe
+// latency would climb with the database load.
+//
+//   cargo run --release --example load_test
+//
+// LOAD_TEST_BASE_URL (default http://localhost:8000), LOAD_TEST_PATH (default a mission search),
+// LOAD_TEST_CONCURRENCY (default 64), LOAD_TEST_REQUESTS (default 2000) and
+// LOAD_TEST_MAX_PROBE_P99_MILLIS (default 250) tune the run. Keep the concurrency above
+// DATABASE_MAX_CONNECTIONS so requests also queue for the pool.
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use tokio::task::JoinSet;
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn percentile(samples: &mut [Duration], percent: usize) -> Duration {
+    if samples.is_empty() {
+        return Duration::ZERO;
+    }
+    samples.sort();
+    samples[(samples.len() - 1) * percent / 100]
+}
+
+async fn probe(client: &reqwest::Client, url: &str, rounds: usize) -> Vec<Duration> {
+    let mut samples = Vec::with_capacity(rounds);
+    for _ in 0..rounds {
+        let started = Instant::now();
+        if client.get(url).send().await.is_ok() {
+            samples.push(started.elapsed());
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    samples
+}
+
+fn spawn_search(load: &mut JoinSet<(bool, Duration)>, client: &reqwest::Client, url: &str) {
+    let (client, url) = (client.clone(), url.to_string());
+    load.spawn(async move {
+        let started = Instant::now();
+        let ok = client
+            .get(&url)
+            .send()
+            .await
+            .is_ok_and(|response| response.status().is_success());
+        (ok, started.elapsed())
+    });
+}
+
+#[tokio::main]
+async fn main() {
+    let base_url = env::var("LOAD_TEST_BASE_URL").unwrap_or("http://localhost:8000".to_string());
+    let concurrency = env_or("LOAD_TEST_CONCURRENCY", 64usize);
+    let requests = env_or("LOAD_TEST_REQUESTS", 2000usize);
+    let max_probe_p99 = Duration::from_millis(env_or("LOAD_TEST_MAX_PROBE_P99_MILLIS", 250u64));
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .pool_max_idle_per_host(concurrency)
+        .build()
+        .expect("Failed to build HTTP client");
+    let probe_url = format!("{}/health_check", base_url);
+    let load_url = format!(
+        "{}{}",
+        base_url,
+        env::var("LOAD_TEST_PATH")
+            .unwrap_or("/api/view/filter?statuses=Open,InProgress&sort=MostCrew".to_string())
+    );
+
+    let mut idle = probe(&client, &probe_url, 50).await;
+    println!("probe p99 while idle: {:?}", percentile(&mut idle, 99));
+
+    // Keep `concurrency` searches in flight until `requests` have been sent
+    let mut load = JoinSet::new();
+    let started = Instant::now();
+    for _ in 0..concurrency.min(requests) {
+        spawn_search(&mut load, &client, &load_url);
+    }
+    let mut remaining = requests - load.len();
+
+    let probing = {
+        let (client, url) = (client.clone(), probe_url.clone());
+        tokio::spawn(async move { probe(&client, &url, 200).await })
+    };
+
+    let (mut failures, mut latencies) = (0usize, Vec::with_capacity(requests));
+    while let Some(result) = load.join_next().await {
+        let (ok, elapsed) = result.expect("Load task panicked");
+        if !ok {
+            failures += 1;
+        }
+        latencies.push(elapsed);
+
+        if remaining > 0 {
+            remaining -= 1;
+            spawn_search(&mut load, &client, &load_url);
+        }
+    }
+    let elapsed = started.elapsed();
+    let mut loaded = probing.await.expect("Probe task panicked");
+
+    println!(
+        "{} search requests, {} concurrent, {} failed in {:?} ({:.0} req/s)",
+        latencies.len(),
+        concurrency,
+        failures,
+        elapsed,
+        latencies.len() as f64 / elapsed.as_secs_f64()
+    );
+    println!(
+        "search p50 {:?}, p99 {:?}",
+        percentile(&mut latencies, 50),
+        percentile(&mut latencies, 99)
+    );
+
+    let loaded_p99 = percentile(&mut loaded, 99);
+    println!("probe p99 under load: {:?}", loaded_p99);
+
+    if failures > 0 || loaded_p99 > max_probe_p99 {
+        eprintln!(
+            "FAILED: probe p99 must stay under {:?} with no failed requests",
+            max_probe_p99
+        );
+        std::process::exit(1);
+    }
+    println!("OK");
+}
@@ -1 +1,2 @@
+pub mod subscribers;
 pub mod use_cases;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        events::{DomainEvent, EventSubscriber},
+        value_object::mission_statuses::MissionStatuses,
+    },
+    infrastructure::metrics::Metrics,
+};
+
+// Counts business activity from the outbox, so a counter moves once per committed event
+pub struct MetricsSubscriber {
+    metrics: Arc<Metrics>,
+}
+
+impl MetricsSubscriber {
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        Self { metrics }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for MetricsSubscriber {
+    fn name(&self) -> &'static str {
+        "metrics"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<()> {
+        match event {
+            DomainEvent::MissionCreated { .. } => self.metrics.missions_created.inc(),
+            DomainEvent::CrewJoined { .. } => self.metrics.crew_joins.inc(),
+            DomainEvent::MissionStatusChanged {
+                status: MissionStatuses::Completed,
+                ..
+            } => self.metrics.missions_completed.inc(),
+            DomainEvent::MessageSent { .. } => self.metrics.messages_sent.inc(),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
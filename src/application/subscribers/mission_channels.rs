@@ -0,0 +1,82 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::{
+    events::{DomainEvent, EventSubscriber},
+    repositories::conversations::ConversationRepository,
+};
+
+pub struct MissionChannelSubscriber<T>
+where
+    T: ConversationRepository + Send + Sync,
+{
+    conversation_repository: Arc<T>,
+}
+
+impl<T> MissionChannelSubscriber<T>
+where
+    T: ConversationRepository + Send + Sync,
+{
+    pub fn new(conversation_repository: Arc<T>) -> Self {
+        Self {
+            conversation_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<T> EventSubscriber for MissionChannelSubscriber<T>
+where
+    T: ConversationRepository + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        "mission_channels"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<()> {
+        match event {
+            DomainEvent::MissionCreated {
+                mission_id,
+                name,
+                chief_id,
+            } => {
+                self.conversation_repository
+                    .create_mission_channel(*mission_id, name.clone(), *chief_id)
+                    .await?;
+            }
+            DomainEvent::CrewJoined {
+                mission_id,
+                mission_name,
+                chief_id,
+                brawler_id,
+            } => {
+                let conversation_id = self
+                    .conversation_repository
+                    .create_mission_channel(*mission_id, mission_name.clone(), *chief_id)
+                    .await?;
+                self.conversation_repository
+                    .sync_crew_member(conversation_id, *mission_id, *brawler_id)
+                    .await?;
+            }
+            DomainEvent::CrewLeft {
+                mission_id,
+                brawler_id,
+                ..
+            } => {
+                if let Some(conversation) = self
+                    .conversation_repository
+                    .find_by_mission(*mission_id)
+                    .await?
+                {
+                    self.conversation_repository
+                        .sync_crew_member(conversation.id, *mission_id, *brawler_id)
+                        .await?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
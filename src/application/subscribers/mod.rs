@@ -0,0 +1,5 @@
+pub mod metrics;
+pub mod mission_channels;
+pub mod notifications;
+pub mod progression;
+pub mod webhooks;
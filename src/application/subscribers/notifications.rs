@@ -0,0 +1,111 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::notifications::AddNotificationEntity,
+    events::{DomainEvent, EventSubscriber},
+    repositories::notifications::NotificationRepository,
+    value_object::notification_kinds::NotificationKinds,
+};
+
+pub struct NotificationSubscriber<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    notification_repository: Arc<T>,
+}
+
+impl<T> NotificationSubscriber<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    pub fn new(notification_repository: Arc<T>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<T> EventSubscriber for NotificationSubscriber<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        "notifications"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<()> {
+        let notifications = match event {
+            DomainEvent::CrewJoined {
+                mission_id,
+                mission_name,
+                chief_id,
+                brawler_id,
+            } => vec![AddNotificationEntity::new(
+                *chief_id,
+                Some(*brawler_id),
+                Some(*mission_id),
+                NotificationKinds::CrewJoined,
+                format!("A new crew member joined your mission '{}'", mission_name),
+            )],
+            DomainEvent::CrewLeft {
+                mission_id,
+                mission_name,
+                chief_id,
+                brawler_id,
+            } => vec![AddNotificationEntity::new(
+                *chief_id,
+                Some(*brawler_id),
+                Some(*mission_id),
+                NotificationKinds::CrewLeft,
+                format!("A crew member left your mission '{}'", mission_name),
+            )],
+            DomainEvent::MissionStatusChanged {
+                mission_id,
+                name,
+                chief_id,
+                status,
+                crew_ids,
+            } => crew_ids
+                .iter()
+                .map(|brawler_id| {
+                    AddNotificationEntity::new(
+                        *brawler_id,
+                        Some(*chief_id),
+                        Some(*mission_id),
+                        NotificationKinds::MissionStatusChanged,
+                        format!("Mission '{}' is now {}", name, status),
+                    )
+                })
+                .collect(),
+            DomainEvent::FriendAdded {
+                brawler_id,
+                friend_id,
+            } => vec![AddNotificationEntity::new(
+                *friend_id,
+                Some(*brawler_id),
+                None,
+                NotificationKinds::FriendAdded,
+                "Someone added you as a friend".to_string(),
+            )],
+            DomainEvent::MessageSent {
+                sender_id,
+                receiver_id,
+                ..
+            } => vec![AddNotificationEntity::new(
+                *receiver_id,
+                Some(*sender_id),
+                None,
+                NotificationKinds::MessageReceived,
+                "You have a new message".to_string(),
+            )],
+            DomainEvent::MissionCreated { .. } => Vec::new(),
+        };
+
+        self.notification_repository.add(notifications).await?;
+
+        Ok(())
+    }
+}
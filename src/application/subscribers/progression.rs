@@ -0,0 +1,106 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::progression::refresh_achievements,
+    domain::{
+        constants::{
+            MISSION_CHIEF_BONUS_XP, MISSION_COMPLETION_BASE_XP, MISSION_COMPLETION_XP_PER_CREW,
+        },
+        entities::progression::AddXpAwardEntity,
+        events::{DomainEvent, EventSubscriber},
+        repositories::progression::ProgressionRepository,
+        value_object::mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct ProgressionSubscriber<T>
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    progression_repository: Arc<T>,
+}
+
+impl<T> ProgressionSubscriber<T>
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    pub fn new(progression_repository: Arc<T>) -> Self {
+        Self {
+            progression_repository,
+        }
+    }
+
+    async fn award_completion(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        crew_ids: &[i32],
+    ) -> Result<()> {
+        // Bigger crews earn more, so everyone on the mission shares the same scaled reward
+        let completion_xp = MISSION_COMPLETION_BASE_XP
+            + MISSION_COMPLETION_XP_PER_CREW * i32::try_from(crew_ids.len())?;
+
+        let mut awards = crew_ids
+            .iter()
+            .map(|brawler_id| AddXpAwardEntity {
+                brawler_id: *brawler_id,
+                mission_id,
+                amount: completion_xp,
+                reason: "MissionCompleted".to_string(),
+            })
+            .collect::<Vec<_>>();
+        awards.push(AddXpAwardEntity {
+            brawler_id: chief_id,
+            mission_id,
+            amount: completion_xp,
+            reason: "MissionCompleted".to_string(),
+        });
+        awards.push(AddXpAwardEntity {
+            brawler_id: chief_id,
+            mission_id,
+            amount: MISSION_CHIEF_BONUS_XP,
+            reason: "MissionLed".to_string(),
+        });
+
+        self.progression_repository.award_xp(awards).await?;
+
+        refresh_achievements(self.progression_repository.as_ref(), chief_id).await?;
+        for brawler_id in crew_ids {
+            refresh_achievements(self.progression_repository.as_ref(), *brawler_id).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T> EventSubscriber for ProgressionSubscriber<T>
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        "progression"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<()> {
+        match event {
+            DomainEvent::MissionStatusChanged {
+                mission_id,
+                chief_id,
+                status: MissionStatuses::Completed,
+                crew_ids,
+                ..
+            } => {
+                self.award_completion(*mission_id, *chief_id, crew_ids)
+                    .await
+            }
+            DomainEvent::CrewJoined { brawler_id, .. } => {
+                refresh_achievements(self.progression_repository.as_ref(), *brawler_id).await?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
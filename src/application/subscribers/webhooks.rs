@@ -0,0 +1,93 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::{
+    events::{DomainEvent, EventSubscriber},
+    repositories::webhooks::WebhookRepository,
+    value_object::{
+        mission_statuses::MissionStatuses,
+        webhook_events::WebhookEvents,
+        webhook_model::{CrewWebhookPayload, MissionWebhookPayload},
+    },
+};
+
+pub struct WebhookSubscriber<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    webhook_repository: Arc<T>,
+}
+
+impl<T> WebhookSubscriber<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    pub fn new(webhook_repository: Arc<T>) -> Self {
+        Self { webhook_repository }
+    }
+}
+
+#[async_trait]
+impl<T> EventSubscriber for WebhookSubscriber<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<()> {
+        let (mission_id, webhook_event, payload) = match event {
+            DomainEvent::MissionCreated {
+                mission_id,
+                name,
+                chief_id,
+            } => (
+                *mission_id,
+                WebhookEvents::MissionCreated,
+                serde_json::to_value(MissionWebhookPayload {
+                    mission_id: *mission_id,
+                    name: name.clone(),
+                    status: MissionStatuses::Open.to_string(),
+                    chief_id: *chief_id,
+                })?,
+            ),
+            DomainEvent::MissionStatusChanged {
+                mission_id,
+                name,
+                chief_id,
+                status,
+                ..
+            } => (
+                *mission_id,
+                WebhookEvents::MissionStatusChanged,
+                serde_json::to_value(MissionWebhookPayload {
+                    mission_id: *mission_id,
+                    name: name.clone(),
+                    status: status.to_string(),
+                    chief_id: *chief_id,
+                })?,
+            ),
+            DomainEvent::CrewJoined {
+                mission_id,
+                brawler_id,
+                ..
+            } => (
+                *mission_id,
+                WebhookEvents::CrewJoined,
+                serde_json::to_value(CrewWebhookPayload {
+                    mission_id: *mission_id,
+                    brawler_id: *brawler_id,
+                })?,
+            ),
+            _ => return Ok(()),
+        };
+
+        self.webhook_repository
+            .enqueue(mission_id, webhook_event.to_string(), payload)
+            .await?;
+
+        Ok(())
+    }
+}
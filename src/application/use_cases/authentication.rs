@@ -2,6 +2,7 @@ use anyhow::Result;
 use std::sync::Arc;
 
 use crate::{
+    config::config_model::AppConfig,
     domain::repositories::brawlers::BrawlerRepository,
     infrastructure::{
         self,
@@ -14,14 +15,18 @@ where
     T: BrawlerRepository + Send + Sync,
 {
     brawler_repository: Arc<T>,
+    config: Arc<AppConfig>,
 }
 
 impl<T> AuthenticationUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(brawler_repository: Arc<T>, config: Arc<AppConfig>) -> Self {
+        Self {
+            brawler_repository,
+            config,
+        }
     }
     pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
         let username = login_model.username.clone();
@@ -39,6 +44,7 @@ where
             brawler_entity.display_name,
             brawler_entity.tag,
             brawler_entity.avatar_url,
+            &self.config.jwt,
         )?;
 
         Ok(passport)
@@ -1,9 +1,12 @@
 use crate::{
+    config::config_model::{AppConfig, ImageBackend},
     domain::{
         repositories::brawlers::BrawlerRepository,
         value_object::{
-            base64_image::Base64Image, brawler_model::RegisterBrawlerModel,
-            mission_moddel::MissionModel, upload_image::UploadedImage,
+            base64_image::Base64Image,
+            brawler_model::{BrawlerProfileModel, RegisterBrawlerModel},
+            mission_moddel::MissionModel,
+            upload_image::UploadedImage,
         },
     },
     infrastructure::{argon2::hash, cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
@@ -16,14 +19,18 @@ where
     T: BrawlerRepository + Send + Sync,
 {
     brawler_repository: Arc<T>,
+    config: Arc<AppConfig>,
 }
 
 impl<T> BrawlersUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(brawler_repository: Arc<T>, config: Arc<AppConfig>) -> Self {
+        Self {
+            brawler_repository,
+            config,
+        }
     }
 
     pub async fn register(&self, mut register_model: RegisterBrawlerModel) -> Result<Passport> {
@@ -36,7 +43,13 @@ where
 
         let user_id = self.brawler_repository.register(register_entity).await?;
 
-        let passport = Passport::new(user_id, register_model.display_name, tag, None)?;
+        let passport = Passport::new(
+            user_id,
+            register_model.display_name,
+            tag,
+            None,
+            &self.config.jwt,
+        )?;
         Ok(passport)
     }
     pub async fn upload_base64image(
@@ -49,10 +62,13 @@ where
             public_id: Some(user_id.to_string()),
             transformation: Some("c_scale,w_256".to_string()),
         };
+        if let ImageBackend::Disabled = self.config.images {
+            return Err(anyhow::anyhow!("Image uploads are not configured"));
+        }
         let base64_image_vo = Base64Image::new(base64_image)?;
         let uploaded_image = self
             .brawler_repository
-            .upload_base64image(user_id, base64_image_vo, opt)
+            .upload_base64image(user_id, base64_image_vo, opt, self.config.images.clone())
             .await?;
 
         Ok(uploaded_image)
@@ -65,9 +81,23 @@ where
         Ok(())
     }
 
+    pub async fn reset_password(&self, brawler_id: i32, new_password: String) -> Result<()> {
+        let hashed_password = hash(new_password)?;
+
+        self.brawler_repository
+            .update_password(brawler_id, hashed_password)
+            .await
+    }
+
     pub async fn get_my_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
         let result = self.brawler_repository.get_missions(brawler_id).await?;
 
         Ok(result)
     }
+
+    pub async fn get_profile(&self, brawler_id: i32) -> Result<BrawlerProfileModel> {
+        let result = self.brawler_repository.get_profile(brawler_id).await?;
+
+        Ok(result)
+    }
 }
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::domain::{
+    repositories::{calendar::CalendarRepository, mission_viewing::MissionViewingRepository},
+    value_object::{calendar_model::CalendarTokenModel, mission_moddel::MissionModel},
+};
+
+pub struct CalendarUseCase<T1, T2>
+where
+    T1: CalendarRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    calendar_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> CalendarUseCase<T1, T2>
+where
+    T1: CalendarRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(calendar_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            calendar_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    // Only a hash is stored, so a leaked database cannot be used to read anyone's feed
+    fn hash_token(token: &str) -> String {
+        Sha256::digest(token.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    pub async fn rotate_token(&self, brawler_id: i32) -> Result<CalendarTokenModel> {
+        let bytes: [u8; 24] = rand::rng().random();
+        let token = bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        self.calendar_repository
+            .save_token_hash(brawler_id, Self::hash_token(&token))
+            .await?;
+
+        Ok(CalendarTokenModel {
+            feed_path: format!("/api/calendar/feed/{}.ics", token),
+            token,
+        })
+    }
+
+    pub async fn revoke_token(&self, brawler_id: i32) -> Result<()> {
+        self.calendar_repository.revoke_token(brawler_id).await
+    }
+
+    pub async fn feed(&self, token: &str) -> Result<Vec<MissionModel>> {
+        let brawler_id = self
+            .calendar_repository
+            .find_brawler_by_token_hash(Self::hash_token(token))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Calendar feed not found"))?;
+
+        self.calendar_repository.missions_of(brawler_id).await
+    }
+
+    pub async fn mission(&self, mission_id: i32) -> Result<MissionModel> {
+        self.mission_viewing_repository
+            .view_detail(mission_id)
+            .await
+    }
+}
@@ -0,0 +1,83 @@
+use crate::domain::entities::conversations::{ConversationEntity, ConversationMessage};
+use crate::domain::repositories::conversations::ConversationRepository;
+use crate::domain::value_object::conversation_model::ConversationSummaryModel;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ConversationUseCase<R>
+where
+    R: ConversationRepository + Send + Sync,
+{
+    repository: Arc<R>,
+}
+
+impl<R> ConversationUseCase<R>
+where
+    R: ConversationRepository + Send + Sync,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    async fn ensure_member(&self, conversation_id: i32, brawler_id: i32) -> Result<()> {
+        if !self
+            .repository
+            .is_member(conversation_id, brawler_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("You are not a member of this channel"));
+        }
+        Ok(())
+    }
+
+    pub async fn get_channels(&self, brawler_id: i32) -> Result<Vec<ConversationSummaryModel>> {
+        self.repository.get_channels(brawler_id).await
+    }
+
+    pub async fn get_mission_channel(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<ConversationEntity> {
+        let conversation = self
+            .repository
+            .find_by_mission(mission_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Mission has no channel"))?;
+
+        self.ensure_member(conversation.id, brawler_id).await?;
+
+        Ok(conversation)
+    }
+
+    pub async fn send_message(
+        &self,
+        conversation_id: i32,
+        sender_id: i32,
+        content: String,
+    ) -> Result<ConversationMessage> {
+        if content.trim().is_empty() {
+            return Err(anyhow::anyhow!("Message cannot be empty"));
+        }
+
+        self.ensure_member(conversation_id, sender_id).await?;
+
+        self.repository
+            .send_message(conversation_id, sender_id, content)
+            .await
+    }
+
+    pub async fn get_messages(
+        &self,
+        conversation_id: i32,
+        brawler_id: i32,
+    ) -> Result<Vec<ConversationMessage>> {
+        self.ensure_member(conversation_id, brawler_id).await?;
+
+        // Move the read cursor when viewing the channel
+        self.repository
+            .mark_as_read(conversation_id, brawler_id)
+            .await?;
+        self.repository.get_messages(conversation_id).await
+    }
+}
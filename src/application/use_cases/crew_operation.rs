@@ -1,38 +1,51 @@
+use crate::config::config_model::AppConfig;
 use crate::domain::{
     entities::crew_memberships::CrewMemberShips,
+    events::DomainEvent,
     repositories::{
         crew_oparation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+        outbox::OutboxRepository, transaction_provider::TransactionProvider,
     },
     value_object::mission_statuses::MissionStatuses,
 };
 use anyhow::Result;
+use diesel::PgConnection;
 use std::sync::Arc;
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T1, T2, T3>
 where
     T1: CrewOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    outbox_repository: Arc<T3>,
+    config: Arc<AppConfig>,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T1, T2, T3> CrewOperationUseCase<T1, T2, T3>
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        outbox_repository: Arc<T3>,
+        config: Arc<AppConfig>,
+    ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
+            outbox_repository,
+            config,
         }
     }
 
     pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
+        let max_crew_per_mission = self.config.missions.max_crew_per_mission;
 
         let mission = self
             .mission_viewing_repository
@@ -60,14 +73,30 @@ where
             return Err(anyhow::anyhow!("Mission is full"));
         }
 
-        self.crew_operation_repository
-            .join(CrewMemberShips {
-                mission_id,
-                brawler_id,
-            })
-            .await?;
+        let crew_operation_repository = Arc::clone(&self.crew_operation_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+
+        self.outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<()> {
+                crew_operation_repository.join_in_transaction(
+                    conn,
+                    CrewMemberShips {
+                        mission_id,
+                        brawler_id,
+                    },
+                )?;
 
-        Ok(())
+                outbox_repository.stage(
+                    conn,
+                    vec![DomainEvent::CrewJoined {
+                        mission_id,
+                        mission_name: mission.name,
+                        chief_id: mission.chief_id,
+                        brawler_id,
+                    }],
+                )
+            }))
+            .await
     }
 
     pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
@@ -81,13 +110,29 @@ where
         if !leaving_condition {
             return Err(anyhow::anyhow!("Mission is not leavable"));
         }
-        self.crew_operation_repository
-            .leave(CrewMemberShips {
-                mission_id,
-                brawler_id,
-            })
-            .await?;
+        let crew_operation_repository = Arc::clone(&self.crew_operation_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+
+        self.outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<()> {
+                crew_operation_repository.leave_in_transaction(
+                    conn,
+                    CrewMemberShips {
+                        mission_id,
+                        brawler_id,
+                    },
+                )?;
 
-        Ok(())
+                outbox_repository.stage(
+                    conn,
+                    vec![DomainEvent::CrewLeft {
+                        mission_id,
+                        mission_name: mission.name,
+                        chief_id: mission.chief_id,
+                        brawler_id,
+                    }],
+                )
+            }))
+            .await
     }
 }
@@ -4,27 +4,40 @@ use std::sync::Arc;
 use crate::domain::{
     entities::brawlers::BrawlerEntity,
     entities::friendships::NewFriendshipEntity,
-    repositories::{brawlers::BrawlerRepository, friendships::FriendshipRepository},
+    events::DomainEvent,
+    repositories::{
+        brawlers::BrawlerRepository, friendships::FriendshipRepository, outbox::OutboxRepository,
+        transaction_provider::TransactionProvider,
+    },
 };
+use diesel::PgConnection;
 
-pub struct FriendshipUseCase<FR, BR>
+pub struct FriendshipUseCase<FR, BR, OR>
 where
     FR: FriendshipRepository + Send + Sync,
     BR: BrawlerRepository + Send + Sync,
+    OR: OutboxRepository + TransactionProvider + Send + Sync,
 {
     friendship_repository: Arc<FR>,
     brawler_repository: Arc<BR>,
+    outbox_repository: Arc<OR>,
 }
 
-impl<FR, BR> FriendshipUseCase<FR, BR>
+impl<FR, BR, OR> FriendshipUseCase<FR, BR, OR>
 where
-    FR: FriendshipRepository + Send + Sync,
+    FR: FriendshipRepository + Send + Sync + 'static,
     BR: BrawlerRepository + Send + Sync,
+    OR: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
-    pub fn new(friendship_repository: Arc<FR>, brawler_repository: Arc<BR>) -> Self {
+    pub fn new(
+        friendship_repository: Arc<FR>,
+        brawler_repository: Arc<BR>,
+        outbox_repository: Arc<OR>,
+    ) -> Self {
         Self {
             friendship_repository,
             brawler_repository,
+            outbox_repository,
         }
     }
 
@@ -69,19 +82,30 @@ where
             status: "accepted".to_string(), // Direct add for simplicity
         };
 
-        self.friendship_repository.add_friend(friendship).await?;
-
         // Bidirectional friendship
         let reverse_friendship = NewFriendshipEntity {
             brawler_id: friend_id,
             friend_id: brawler_id,
             status: "accepted".to_string(),
         };
-        self.friendship_repository
-            .add_friend(reverse_friendship)
-            .await?;
 
-        Ok(())
+        let friendship_repository = Arc::clone(&self.friendship_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+
+        self.outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<()> {
+                friendship_repository.add_friend_in_transaction(conn, friendship)?;
+                friendship_repository.add_friend_in_transaction(conn, reverse_friendship)?;
+
+                outbox_repository.stage(
+                    conn,
+                    vec![DomainEvent::FriendAdded {
+                        brawler_id,
+                        friend_id,
+                    }],
+                )
+            }))
+            .await
     }
 
     pub async fn get_friends(&self, brawler_id: i32) -> Result<Vec<BrawlerEntity>> {
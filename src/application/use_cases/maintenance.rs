@@ -0,0 +1,38 @@
+use anyhow::Result;
+use chrono::{TimeDelta, Utc};
+use std::sync::Arc;
+
+use crate::domain::{
+    repositories::maintenance::MaintenanceRepository,
+    value_object::maintenance_model::PurgeReportModel,
+};
+
+pub struct MaintenanceUseCase<T>
+where
+    T: MaintenanceRepository + Send + Sync,
+{
+    maintenance_repository: Arc<T>,
+}
+
+impl<T> MaintenanceUseCase<T>
+where
+    T: MaintenanceRepository + Send + Sync,
+{
+    pub fn new(maintenance_repository: Arc<T>) -> Self {
+        Self {
+            maintenance_repository,
+        }
+    }
+
+    pub async fn purge_deleted(&self, older_than_days: i64) -> Result<PurgeReportModel> {
+        if older_than_days < 0 {
+            return Err(anyhow::anyhow!("older_than_days cannot be negative"));
+        }
+
+        let deleted_before = Utc::now().naive_utc() - TimeDelta::days(older_than_days);
+
+        self.maintenance_repository
+            .purge_deleted(deleted_before)
+            .await
+    }
+}
@@ -1,15 +1,27 @@
 use crate::domain::entities::messages::Message;
+use crate::domain::events::DomainEvent;
 use crate::domain::repositories::messages::MessageRepository;
+use crate::domain::repositories::outbox::OutboxRepository;
+use crate::domain::repositories::transaction_provider::TransactionProvider;
 use anyhow::Result;
+use diesel::PgConnection;
 use std::sync::Arc;
 
-pub struct MessageUseCase<R: MessageRepository> {
+pub struct MessageUseCase<R: MessageRepository, O: OutboxRepository + TransactionProvider> {
     repository: Arc<R>,
+    outbox_repository: Arc<O>,
 }
 
-impl<R: MessageRepository> MessageUseCase<R> {
-    pub fn new(repository: Arc<R>) -> Self {
-        Self { repository }
+impl<R, O> MessageUseCase<R, O>
+where
+    R: MessageRepository + 'static,
+    O: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    pub fn new(repository: Arc<R>, outbox_repository: Arc<O>) -> Self {
+        Self {
+            repository,
+            outbox_repository,
+        }
     }
 
     pub async fn send_message(
@@ -18,8 +30,31 @@ impl<R: MessageRepository> MessageUseCase<R> {
         receiver_id: i32,
         content: String,
     ) -> Result<Message> {
-        self.repository
-            .send_message(sender_id, receiver_id, content)
+        let repository = Arc::clone(&self.repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+
+        self.outbox_repository
+            .transaction(Box::new(
+                move |conn: &mut PgConnection| -> Result<Message> {
+                    let message = repository.send_message_in_transaction(
+                        conn,
+                        sender_id,
+                        receiver_id,
+                        content,
+                    )?;
+
+                    outbox_repository.stage(
+                        conn,
+                        vec![DomainEvent::MessageSent {
+                            message_id: message.id,
+                            sender_id,
+                            receiver_id,
+                        }],
+                    )?;
+
+                    Ok(message)
+                },
+            ))
             .await
     }
 
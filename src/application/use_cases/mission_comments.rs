@@ -0,0 +1,169 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    constants::MAX_COMMENT_LENGTH,
+    repositories::{
+        mission_comments::MissionCommentRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_object::{
+        comment_visibility::CommentVisibility,
+        mission_comment_model::{
+            AddMissionCommentModel, EditMissionCommentModel, MissionCommentModel,
+        },
+        mission_moddel::MissionModel,
+        pagination::{PaginatedModel, Pagination},
+    },
+};
+
+pub struct MissionCommentUseCase<T1, T2>
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_comment_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionCommentUseCase<T1, T2>
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_comment_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_comment_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    async fn is_participant(&self, mission: &MissionModel, brawler_id: i32) -> Result<bool> {
+        if mission.chief_id == brawler_id {
+            return Ok(true);
+        }
+
+        self.mission_viewing_repository
+            .is_crew_member(mission.id, brawler_id)
+            .await
+    }
+
+    fn validate_content(content: &str) -> Result<()> {
+        if content.trim().is_empty() {
+            return Err(anyhow::anyhow!("Comment cannot be empty"));
+        }
+        if content.trim().chars().count() > MAX_COMMENT_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Comment must be at most {} characters long",
+                MAX_COMMENT_LENGTH
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn get_thread(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        pagination: &Pagination,
+    ) -> Result<PaginatedModel<MissionCommentModel>> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if mission.comment_visibility == CommentVisibility::CrewOnly.to_string()
+            && !self.is_participant(&mission, brawler_id).await?
+        {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can read this mission's comments"
+            ));
+        }
+
+        let comments = self
+            .mission_comment_repository
+            .gets(mission_id, pagination)
+            .await?;
+        let total = self.mission_comment_repository.counting(mission_id).await?;
+
+        Ok(PaginatedModel::new(comments, pagination, total))
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        add_mission_comment_model: AddMissionCommentModel,
+    ) -> Result<i32> {
+        Self::validate_content(&add_mission_comment_model.content)?;
+
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if !self.is_participant(&mission, brawler_id).await? {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can comment on this mission"
+            ));
+        }
+
+        let result = self
+            .mission_comment_repository
+            .add(add_mission_comment_model.to_entity(mission_id, brawler_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        comment_id: i32,
+        brawler_id: i32,
+        edit_mission_comment_model: EditMissionCommentModel,
+    ) -> Result<i32> {
+        Self::validate_content(&edit_mission_comment_model.content)?;
+
+        let comment = self
+            .mission_comment_repository
+            .find_by_id(comment_id)
+            .await?;
+
+        if comment.brawler_id != brawler_id {
+            return Err(anyhow::anyhow!("Only the author can edit this comment"));
+        }
+
+        let result = self
+            .mission_comment_repository
+            .edit(
+                comment_id,
+                brawler_id,
+                edit_mission_comment_model.to_entity(),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, comment_id: i32, brawler_id: i32) -> Result<()> {
+        let comment = self
+            .mission_comment_repository
+            .find_by_id(comment_id)
+            .await?;
+
+        if comment.brawler_id != brawler_id {
+            let mission = self
+                .mission_viewing_repository
+                .view_detail(comment.mission_id)
+                .await?;
+
+            if mission.chief_id != brawler_id {
+                return Err(anyhow::anyhow!(
+                    "Only the author or the chief can remove this comment"
+                ));
+            }
+        }
+
+        self.mission_comment_repository.remove(comment_id).await?;
+        Ok(())
+    }
+}
@@ -1,34 +1,52 @@
 use std::sync::Arc;
 
 use crate::domain::{
+    entities::mission_series::AddMissionSeriesEntity,
+    events::DomainEvent,
     repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+        mission_management::MissionManagementRepository, mission_series::MissionSeriesRepository,
+        mission_viewing::MissionViewingRepository, outbox::OutboxRepository,
+        transaction_provider::TransactionProvider,
+    },
+    value_object::{
+        mission_moddel::{AddMissionModel, EditMissionModel},
+        tag_model::normalize_tags,
     },
-    value_object::mission_moddel::{AddMissionModel, EditMissionModel},
 };
 use anyhow::Result;
+use diesel::PgConnection;
 
-pub struct MissionManagementUseCase<T1, T2>
+pub struct MissionManagementUseCase<T1, T2, T3, T4>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync,
+    T4: MissionSeriesRepository + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    outbox_repository: Arc<T3>,
+    mission_series_repository: Arc<T4>,
 }
 
-impl<T1, T2> MissionManagementUseCase<T1, T2>
+impl<T1, T2, T3, T4> MissionManagementUseCase<T1, T2, T3, T4>
 where
-    T1: MissionManagementRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+    T4: MissionSeriesRepository + Send + Sync + 'static,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
+        outbox_repository: Arc<T3>,
+        mission_series_repository: Arc<T4>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
+            outbox_repository,
+            mission_series_repository,
         }
     }
     pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
@@ -37,11 +55,59 @@ where
                 "Mission name must be least 4 characters long"
             ));
         }
-        let insert_mission_entity = add_mission_model.to_entity(chief_id);
+        if add_mission_model
+            .deadline
+            .is_some_and(|deadline| deadline <= chrono::Utc::now().naive_utc())
+        {
+            return Err(anyhow::anyhow!("Mission deadline must be in the future"));
+        }
+        let tags = normalize_tags(&add_mission_model.tags)?;
+        let now = chrono::Utc::now().naive_utc();
+        let recurrence_rule = add_mission_model
+            .recurrence
+            .as_ref()
+            .map(|recurrence| recurrence.to_rule(now))
+            .transpose()?;
+        let mut insert_mission_entity = add_mission_model.to_entity(chief_id);
+
+        let mission_management_repository = Arc::clone(&self.mission_management_repository);
+        let mission_series_repository = Arc::clone(&self.mission_series_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+        let name = add_mission_model.name.clone();
 
         let result = self
-            .mission_management_repository
-            .add(insert_mission_entity)
+            .outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<i32> {
+                // The mission itself is the first occurrence; the scheduler spawns the rest
+                if let Some(rule) = recurrence_rule {
+                    let first_occurrence = rule.first_occurrence(now);
+                    let series_id = mission_series_repository.add_in_transaction(
+                        conn,
+                        AddMissionSeriesEntity::new(
+                            chief_id,
+                            &rule,
+                            rule.next_after(first_occurrence),
+                        ),
+                    )?;
+                    insert_mission_entity.series_id = Some(series_id);
+                    insert_mission_entity.scheduled_for = Some(first_occurrence);
+                }
+
+                let mission_id = mission_management_repository
+                    .add_in_transaction(conn, insert_mission_entity)?;
+                mission_management_repository.set_tags_in_transaction(conn, mission_id, tags)?;
+
+                outbox_repository.stage(
+                    conn,
+                    vec![DomainEvent::MissionCreated {
+                        mission_id,
+                        name,
+                        chief_id,
+                    }],
+                )?;
+
+                Ok(mission_id)
+            }))
             .await?;
 
         Ok(result)
@@ -63,6 +129,17 @@ where
                 edit_mission_model.name = Some(mission_name.trim().to_string());
             }
         }
+        if edit_mission_model
+            .deadline
+            .is_some_and(|deadline| deadline <= chrono::Utc::now().naive_utc())
+        {
+            return Err(anyhow::anyhow!("Mission deadline must be in the future"));
+        }
+        let tags = edit_mission_model
+            .tags
+            .as_deref()
+            .map(normalize_tags)
+            .transpose()?;
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
@@ -75,9 +152,24 @@ where
 
         let edit_mission_entity = edit_mission_model.to_entity(chief_id);
 
+        let mission_management_repository = Arc::clone(&self.mission_management_repository);
+
         let result = self
-            .mission_management_repository
-            .edit(mission_id, edit_mission_entity)
+            .outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<i32> {
+                let mission_id = mission_management_repository.edit_in_transaction(
+                    conn,
+                    mission_id,
+                    edit_mission_entity,
+                )?;
+
+                if let Some(tags) = tags {
+                    mission_management_repository
+                        .set_tags_in_transaction(conn, mission_id, tags)?;
+                }
+
+                Ok(mission_id)
+            }))
             .await?;
 
         Ok(result)
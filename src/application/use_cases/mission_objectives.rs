@@ -0,0 +1,225 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    constants::MAX_OBJECTIVE_TITLE_LENGTH,
+    entities::mission_objectives::{AddMissionObjectiveEntity, MissionObjectiveEntity},
+    repositories::{
+        mission_objectives::MissionObjectiveRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_object::{
+        mission_moddel::MissionModel,
+        mission_objective_model::{
+            AddMissionObjectiveModel, EditMissionObjectiveModel, MissionObjectiveModel,
+        },
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct MissionObjectiveUseCase<T1, T2>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_objective_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionObjectiveUseCase<T1, T2>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_objective_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_objective_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    fn validate_title(title: &str) -> Result<String> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(anyhow::anyhow!("Objective title cannot be empty"));
+        }
+        if title.chars().count() > MAX_OBJECTIVE_TITLE_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Objective title must be at most {} characters long",
+                MAX_OBJECTIVE_TITLE_LENGTH
+            ));
+        }
+
+        Ok(title.to_string())
+    }
+
+    async fn editable_mission(&self, mission_id: i32, chief_id: i32) -> Result<MissionModel> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the Chief (ID: {}) can manage objectives of this mission",
+                mission.chief_id
+            ));
+        }
+
+        let is_status_editable = mission.status == MissionStatuses::Open.to_string()
+            || mission.status == MissionStatuses::InProgress.to_string();
+        if !is_status_editable {
+            return Err(anyhow::anyhow!(
+                "Objectives can only be changed while the mission is 'Open' or 'InProgress' (current: {})",
+                mission.status
+            ));
+        }
+
+        Ok(mission)
+    }
+
+    async fn checkable_objective(
+        &self,
+        objective_id: i32,
+        brawler_id: i32,
+    ) -> Result<MissionObjectiveEntity> {
+        let objective = self
+            .mission_objective_repository
+            .find_by_id(objective_id)
+            .await?;
+
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(objective.mission_id)
+            .await?;
+
+        if mission.status != MissionStatuses::InProgress.to_string() {
+            return Err(anyhow::anyhow!(
+                "Mission must be 'InProgress' to check off objectives (current: {})",
+                mission.status
+            ));
+        }
+
+        let is_participant = mission.chief_id == brawler_id
+            || self
+                .mission_viewing_repository
+                .is_crew_member(mission.id, brawler_id)
+                .await?;
+        if !is_participant {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can check off objectives"
+            ));
+        }
+
+        Ok(objective)
+    }
+
+    // Objectives are visible wherever the mission is: view_detail is the check /view uses, so a
+    // deleted mission is not found here either
+    pub async fn get_objectives(&self, mission_id: i32) -> Result<Vec<MissionObjectiveModel>> {
+        self.mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        let result = self.mission_objective_repository.gets(mission_id).await?;
+
+        Ok(result)
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        add_mission_objective_model: AddMissionObjectiveModel,
+    ) -> Result<i32> {
+        let title = Self::validate_title(&add_mission_objective_model.title)?;
+
+        self.editable_mission(mission_id, chief_id).await?;
+
+        let position = self
+            .mission_objective_repository
+            .next_position(mission_id)
+            .await?;
+
+        let result = self
+            .mission_objective_repository
+            .add(AddMissionObjectiveEntity {
+                mission_id,
+                position,
+                title,
+                is_mandatory: add_mission_objective_model.is_mandatory,
+            })
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        objective_id: i32,
+        chief_id: i32,
+        mut edit_mission_objective_model: EditMissionObjectiveModel,
+    ) -> Result<i32> {
+        if let Some(title) = edit_mission_objective_model.title {
+            edit_mission_objective_model.title = Some(Self::validate_title(&title)?);
+        }
+        if let Some(position) = edit_mission_objective_model.position
+            && position < 1
+        {
+            return Err(anyhow::anyhow!("Objective position must be at least 1"));
+        }
+
+        let objective = self
+            .mission_objective_repository
+            .find_by_id(objective_id)
+            .await?;
+        self.editable_mission(objective.mission_id, chief_id)
+            .await?;
+
+        let result = self
+            .mission_objective_repository
+            .edit(objective_id, edit_mission_objective_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, objective_id: i32, chief_id: i32) -> Result<()> {
+        let objective = self
+            .mission_objective_repository
+            .find_by_id(objective_id)
+            .await?;
+        self.editable_mission(objective.mission_id, chief_id)
+            .await?;
+
+        self.mission_objective_repository
+            .remove(objective_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn complete(&self, objective_id: i32, brawler_id: i32) -> Result<i32> {
+        let objective = self.checkable_objective(objective_id, brawler_id).await?;
+
+        if objective.completed_at.is_some() {
+            return Err(anyhow::anyhow!("Objective is already completed"));
+        }
+
+        let result = self
+            .mission_objective_repository
+            .complete(objective_id, brawler_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn uncomplete(&self, objective_id: i32, brawler_id: i32) -> Result<i32> {
+        self.checkable_objective(objective_id, brawler_id).await?;
+
+        let result = self
+            .mission_objective_repository
+            .uncomplete(objective_id)
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -1,32 +1,52 @@
 use anyhow::Result;
+use diesel::PgConnection;
 use std::sync::Arc;
 
+use crate::config::config_model::AppConfig;
 use crate::domain::{
-    constants::MAX_CREW_PER_MISSION,
+    events::DomainEvent,
     repositories::{
+        mission_objectives::MissionObjectiveRepository,
         mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+        outbox::OutboxRepository, transaction_provider::TransactionProvider,
     },
-    value_object::mission_statuses::MissionStatuses,
+    value_object::{mission_moddel::MissionModel, mission_statuses::MissionStatuses},
 };
 
-pub struct MissionOperationUseCase<T1, T2>
+pub struct MissionOperationUseCase<T1, T2, T3, T4>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionObjectiveRepository + Send + Sync,
+    T4: OutboxRepository + TransactionProvider + Send + Sync,
 {
     mission_operation_repository: Arc<T1>,
     missiom_viewing_repository: Arc<T2>,
+    mission_objective_repository: Arc<T3>,
+    outbox_repository: Arc<T4>,
+    config: Arc<AppConfig>,
 }
 
-impl<T1, T2> MissionOperationUseCase<T1, T2>
+impl<T1, T2, T3, T4> MissionOperationUseCase<T1, T2, T3, T4>
 where
-    T1: MissionOperationRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionObjectiveRepository + Send + Sync,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
-    pub fn new(mission_operation_repository: Arc<T1>, missiom_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        mission_operation_repository: Arc<T1>,
+        missiom_viewing_repository: Arc<T2>,
+        mission_objective_repository: Arc<T3>,
+        outbox_repository: Arc<T4>,
+        config: Arc<AppConfig>,
+    ) -> Self {
         Self {
             mission_operation_repository,
             missiom_viewing_repository,
+            mission_objective_repository,
+            outbox_repository,
+            config,
         }
     }
     pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
@@ -50,16 +70,17 @@ where
             ));
         }
 
-        if crew_count <= 0 {
+        if crew_count == 0 {
             return Err(anyhow::anyhow!(
                 "Mission must have at least one crew member to start"
             ));
         }
 
-        if crew_count > MAX_CREW_PER_MISSION as u32 {
+        let max_crew_per_mission = self.config.missions.max_crew_per_mission;
+        if crew_count > max_crew_per_mission {
             return Err(anyhow::anyhow!(
                 "Mission crew exceeds maximum limit of {}",
-                MAX_CREW_PER_MISSION
+                max_crew_per_mission
             ));
         }
 
@@ -70,11 +91,8 @@ where
             ));
         }
 
-        let result = self
-            .mission_operation_repository
-            .in_progress(mission_id, chief_id)
-            .await?;
-        Ok(result)
+        self.change_status(&mission, chief_id, MissionStatuses::InProgress)
+            .await
     }
     pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let mission = self
@@ -96,12 +114,87 @@ where
             ));
         }
 
-        let result = self
-            .mission_operation_repository
-            .to_completed(mission_id, chief_id)
+        if mission.require_objectives {
+            let incomplete_count = self
+                .mission_objective_repository
+                .count_incomplete_mandatory(mission_id)
+                .await?;
+
+            if incomplete_count > 0 {
+                return Err(anyhow::anyhow!(
+                    "Mission still has {} mandatory objective(s) to complete",
+                    incomplete_count
+                ));
+            }
+        }
+
+        self.change_status(&mission, chief_id, MissionStatuses::Completed)
+            .await
+    }
+
+    async fn change_status(
+        &self,
+        mission: &MissionModel,
+        chief_id: i32,
+        status: MissionStatuses,
+    ) -> Result<i32> {
+        let crew_ids = self
+            .missiom_viewing_repository
+            .get_mission_crew(mission.id)
+            .await?
+            .into_iter()
+            .map(|brawler| brawler.id)
+            .collect();
+
+        let mission_operation_repository = Arc::clone(&self.mission_operation_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+        let event = DomainEvent::MissionStatusChanged {
+            mission_id: mission.id,
+            name: mission.name.clone(),
+            chief_id: mission.chief_id,
+            status: status.clone(),
+            crew_ids,
+        };
+        let mission_id = mission.id;
+        // Guards the update, so only one of two concurrent transitions goes through
+        let expected_from = mission.status.parse::<MissionStatuses>()?;
+
+        self.outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<i32> {
+                let result = mission_operation_repository.set_status_in_transaction(
+                    conn,
+                    mission_id,
+                    chief_id,
+                    expected_from,
+                    status,
+                )?;
+
+                outbox_repository.stage(conn, vec![event])?;
+
+                Ok(result)
+            }))
+            .await
+    }
+
+    // Operator override: skips the chief check and also fails missions that never started
+    pub async fn force_fail(&self, mission_id: i32) -> Result<i32> {
+        let mission = self
+            .missiom_viewing_repository
+            .view_detail(mission_id)
             .await?;
 
-        Ok(result)
+        let is_status_open_or_in_progress = mission.status == MissionStatuses::Open.to_string()
+            || mission.status == MissionStatuses::InProgress.to_string();
+
+        if !is_status_open_or_in_progress {
+            return Err(anyhow::anyhow!(
+                "Mission must be 'Open' or 'InProgress' to fail (current: {})",
+                mission.status
+            ));
+        }
+
+        self.change_status(&mission, mission.chief_id, MissionStatuses::Failed)
+            .await
     }
 
     pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
@@ -124,11 +217,7 @@ where
             ));
         }
 
-        let result = self
-            .mission_operation_repository
-            .to_failed(mission_id, chief_id)
-            .await?;
-
-        Ok(result)
+        self.change_status(&mission, chief_id, MissionStatuses::Failed)
+            .await
     }
 }
@@ -0,0 +1,130 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+use crate::domain::{
+    constants::RATING_WINDOW_DAYS,
+    repositories::{
+        mission_ratings::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_object::{
+        mission_moddel::MissionModel,
+        mission_rating_model::{AddMissionRatingModel, MissionRatingModel, ReputationModel},
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct MissionRatingUseCase<T1, T2>
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_rating_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionRatingUseCase<T1, T2>
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_rating_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_rating_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    async fn is_participant(&self, mission: &MissionModel, brawler_id: i32) -> Result<bool> {
+        if mission.chief_id == brawler_id {
+            return Ok(true);
+        }
+
+        self.mission_viewing_repository
+            .is_crew_member(mission.id, brawler_id)
+            .await
+    }
+
+    pub async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        add_mission_rating_model: AddMissionRatingModel,
+    ) -> Result<i32> {
+        if !(1..=5).contains(&add_mission_rating_model.score) {
+            return Err(anyhow::anyhow!("Score must be between 1 and 5"));
+        }
+        if add_mission_rating_model.ratee_id == rater_id {
+            return Err(anyhow::anyhow!("You cannot rate yourself"));
+        }
+
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        let is_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        let finished_at = match mission.finished_at {
+            Some(finished_at) if is_finished => finished_at,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Mission must be 'Completed' or 'Failed' to rate (current: {})",
+                    mission.status
+                ));
+            }
+        };
+
+        if Utc::now().naive_utc() > finished_at + Duration::days(RATING_WINDOW_DAYS) {
+            return Err(anyhow::anyhow!(
+                "Ratings are only accepted within {} days after the mission ends",
+                RATING_WINDOW_DAYS
+            ));
+        }
+
+        if !self.is_participant(&mission, rater_id).await?
+            || !self
+                .is_participant(&mission, add_mission_rating_model.ratee_id)
+                .await?
+        {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew of this mission can rate each other"
+            ));
+        }
+
+        let existing = self
+            .mission_rating_repository
+            .find(mission_id, rater_id, add_mission_rating_model.ratee_id)
+            .await?;
+        if existing.is_some() {
+            return Err(anyhow::anyhow!(
+                "You have already rated this brawler for this mission"
+            ));
+        }
+
+        let result = self
+            .mission_rating_repository
+            .add(add_mission_rating_model.to_entity(mission_id, rater_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_mission_ratings(&self, mission_id: i32) -> Result<Vec<MissionRatingModel>> {
+        let result = self
+            .mission_rating_repository
+            .gets_by_mission(mission_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel> {
+        let result = self
+            .mission_rating_repository
+            .get_reputation(brawler_id)
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use diesel::PgConnection;
+use tracing::warn;
+
+use crate::{
+    application::use_cases::mission_templates::spawn_mission,
+    domain::{
+        constants::MISSION_SCHEDULER_BATCH_SIZE,
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_objectives::MissionObjectiveRepository,
+            mission_series::MissionSeriesRepository, mission_templates::MissionTemplateRepository,
+            outbox::OutboxRepository, transaction_provider::TransactionProvider,
+        },
+        value_object::recurrence::MissionSeriesModel,
+    },
+};
+
+pub struct MissionSeriesUseCase<T1, T2, T3, T4, T5>
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionTemplateRepository + Send + Sync,
+    T3: MissionManagementRepository + Send + Sync,
+    T4: MissionObjectiveRepository + Send + Sync,
+    T5: OutboxRepository + TransactionProvider + Send + Sync,
+{
+    mission_series_repository: Arc<T1>,
+    mission_template_repository: Arc<T2>,
+    mission_management_repository: Arc<T3>,
+    mission_objective_repository: Arc<T4>,
+    outbox_repository: Arc<T5>,
+}
+
+impl<T1, T2, T3, T4, T5> MissionSeriesUseCase<T1, T2, T3, T4, T5>
+where
+    T1: MissionSeriesRepository + Send + Sync + 'static,
+    T2: MissionTemplateRepository + Send + Sync + 'static,
+    T3: MissionManagementRepository + Send + Sync + 'static,
+    T4: MissionObjectiveRepository + Send + Sync + 'static,
+    T5: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    pub fn new(
+        mission_series_repository: Arc<T1>,
+        mission_template_repository: Arc<T2>,
+        mission_management_repository: Arc<T3>,
+        mission_objective_repository: Arc<T4>,
+        outbox_repository: Arc<T5>,
+    ) -> Self {
+        Self {
+            mission_series_repository,
+            mission_template_repository,
+            mission_management_repository,
+            mission_objective_repository,
+            outbox_repository,
+        }
+    }
+
+    // Only the chief sees the rule; the missions it spawned follow their own visibility
+    pub async fn get(&self, series_id: i32, brawler_id: i32) -> Result<MissionSeriesModel> {
+        let series = self.mission_series_repository.find(series_id).await?;
+        if series.chief_id != brawler_id {
+            return Err(anyhow::anyhow!("Series not found"));
+        }
+
+        series.to_model()
+    }
+
+    pub async fn stop(&self, series_id: i32, chief_id: i32) -> Result<()> {
+        self.mission_series_repository
+            .stop(series_id, chief_id)
+            .await
+    }
+
+    // Returns how many missions were spawned
+    pub async fn spawn_due(&self) -> Result<usize> {
+        let series_ids = self
+            .mission_series_repository
+            .due_ids(MISSION_SCHEDULER_BATCH_SIZE)
+            .await?;
+
+        // A series that keeps failing is retried on the next tick without holding up the others
+        let mut spawned = 0;
+        for series_id in series_ids {
+            match self.spawn_next(series_id).await {
+                Ok(Some(_)) => spawned += 1,
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "Failed to spawn the next mission of series {}: {:#}",
+                    series_id, e
+                ),
+            }
+        }
+
+        Ok(spawned)
+    }
+
+    // Copies the most recent mission of the series into its next occurrence. A series whose
+    // missions were all removed is switched off instead
+    pub async fn spawn_next(&self, series_id: i32) -> Result<Option<i32>> {
+        let mission_series_repository = Arc::clone(&self.mission_series_repository);
+        let mission_template_repository = Arc::clone(&self.mission_template_repository);
+        let mission_management_repository = Arc::clone(&self.mission_management_repository);
+        let mission_objective_repository = Arc::clone(&self.mission_objective_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+
+        self.outbox_repository
+            .transaction(Box::new(
+                move |conn: &mut PgConnection| -> Result<Option<i32>> {
+                    let Some(series) =
+                        mission_series_repository.lock_due_in_transaction(conn, series_id)?
+                    else {
+                        return Ok(None);
+                    };
+                    let Some(latest) =
+                        mission_series_repository.latest_mission_in_transaction(conn, series_id)?
+                    else {
+                        mission_series_repository.deactivate_in_transaction(conn, series_id)?;
+                        return Ok(None);
+                    };
+
+                    let blueprint = mission_template_repository
+                        .blueprint_of_mission_in_transaction(
+                            conn,
+                            latest.mission_id,
+                            series.chief_id,
+                        )?;
+                    let occurrence = series.next_occurrence_at;
+                    let deadline = latest.deadline_offset.map(|offset| occurrence + offset);
+                    let mut add_mission_entity =
+                        blueprint.to_mission_entity(series.chief_id, deadline);
+                    add_mission_entity.series_id = Some(series_id);
+                    add_mission_entity.scheduled_for = Some(occurrence);
+
+                    let mission_id = spawn_mission(
+                        conn,
+                        mission_management_repository.as_ref(),
+                        mission_objective_repository.as_ref(),
+                        outbox_repository.as_ref(),
+                        &blueprint,
+                        add_mission_entity,
+                    )?;
+
+                    // Occurrences missed while the scheduler was down are skipped, not replayed
+                    let next_occurrence_at = series
+                        .rule()?
+                        .next_after(occurrence.max(chrono::Utc::now().naive_utc()));
+                    mission_series_repository.advance_in_transaction(
+                        conn,
+                        series_id,
+                        next_occurrence_at,
+                    )?;
+
+                    Ok(Some(mission_id))
+                },
+            ))
+            .await
+    }
+}
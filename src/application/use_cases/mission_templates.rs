@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use diesel::PgConnection;
+
+use crate::domain::{
+    constants::MAX_TEMPLATES_PER_BRAWLER,
+    entities::missions::AddMissionEntity,
+    events::DomainEvent,
+    repositories::{
+        mission_management::MissionManagementRepository,
+        mission_objectives::MissionObjectiveRepository,
+        mission_templates::MissionTemplateRepository, outbox::OutboxRepository,
+        transaction_provider::TransactionProvider,
+    },
+    value_object::mission_template_model::{
+        InstantiateMissionModel, MissionBlueprint, MissionTemplateModel, SaveMissionTemplateModel,
+    },
+};
+
+pub struct MissionTemplateUseCase<T1, T2, T3, T4>
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: MissionObjectiveRepository + Send + Sync,
+    T4: OutboxRepository + TransactionProvider + Send + Sync,
+{
+    mission_template_repository: Arc<T1>,
+    mission_management_repository: Arc<T2>,
+    mission_objective_repository: Arc<T3>,
+    outbox_repository: Arc<T4>,
+}
+
+impl<T1, T2, T3, T4> MissionTemplateUseCase<T1, T2, T3, T4>
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    pub fn new(
+        mission_template_repository: Arc<T1>,
+        mission_management_repository: Arc<T2>,
+        mission_objective_repository: Arc<T3>,
+        outbox_repository: Arc<T4>,
+    ) -> Self {
+        Self {
+            mission_template_repository,
+            mission_management_repository,
+            mission_objective_repository,
+            outbox_repository,
+        }
+    }
+
+    pub async fn save_from_mission(
+        &self,
+        owner_id: i32,
+        mission_id: i32,
+        save_mission_template_model: SaveMissionTemplateModel,
+    ) -> Result<i32> {
+        let mut blueprint = self
+            .mission_template_repository
+            .blueprint_of_mission(mission_id, owner_id)
+            .await?;
+        if let Some(name) = save_mission_template_model.name {
+            blueprint.name = valid_name(&name)?;
+        }
+
+        self.mission_template_repository
+            .add(
+                blueprint.to_template_entity(owner_id)?,
+                MAX_TEMPLATES_PER_BRAWLER,
+            )
+            .await
+    }
+
+    pub async fn list(&self, owner_id: i32) -> Result<Vec<MissionTemplateModel>> {
+        let templates = self.mission_template_repository.list(owner_id).await?;
+
+        Ok(templates
+            .iter()
+            .map(|template| template.to_model())
+            .collect())
+    }
+
+    pub async fn get(&self, owner_id: i32, template_id: i32) -> Result<MissionTemplateModel> {
+        let template = self
+            .mission_template_repository
+            .find(template_id, owner_id)
+            .await?;
+
+        Ok(template.to_model())
+    }
+
+    pub async fn remove(&self, owner_id: i32, template_id: i32) -> Result<()> {
+        self.mission_template_repository
+            .remove(template_id, owner_id)
+            .await
+    }
+
+    pub async fn create_mission(
+        &self,
+        chief_id: i32,
+        template_id: i32,
+        instantiate_mission_model: InstantiateMissionModel,
+    ) -> Result<i32> {
+        let blueprint = self
+            .mission_template_repository
+            .find(template_id, chief_id)
+            .await?
+            .to_blueprint();
+
+        self.instantiate(chief_id, blueprint, instantiate_mission_model)
+            .await
+    }
+
+    pub async fn clone_mission(
+        &self,
+        chief_id: i32,
+        mission_id: i32,
+        instantiate_mission_model: InstantiateMissionModel,
+    ) -> Result<i32> {
+        let blueprint = self
+            .mission_template_repository
+            .blueprint_of_mission(mission_id, chief_id)
+            .await?;
+
+        self.instantiate(chief_id, blueprint, instantiate_mission_model)
+            .await
+    }
+
+    async fn instantiate(
+        &self,
+        chief_id: i32,
+        mut blueprint: MissionBlueprint,
+        instantiate_mission_model: InstantiateMissionModel,
+    ) -> Result<i32> {
+        if let Some(name) = instantiate_mission_model.name {
+            blueprint.name = valid_name(&name)?;
+        }
+        if instantiate_mission_model
+            .deadline
+            .is_some_and(|deadline| deadline <= chrono::Utc::now().naive_utc())
+        {
+            return Err(anyhow::anyhow!("Mission deadline must be in the future"));
+        }
+
+        let mission_management_repository = Arc::clone(&self.mission_management_repository);
+        let mission_objective_repository = Arc::clone(&self.mission_objective_repository);
+        let outbox_repository = Arc::clone(&self.outbox_repository);
+
+        self.outbox_repository
+            .transaction(Box::new(move |conn: &mut PgConnection| -> Result<i32> {
+                spawn_mission(
+                    conn,
+                    mission_management_repository.as_ref(),
+                    mission_objective_repository.as_ref(),
+                    outbox_repository.as_ref(),
+                    &blueprint,
+                    blueprint.to_mission_entity(chief_id, instantiate_mission_model.deadline),
+                )
+            }))
+            .await
+    }
+}
+
+// Shared by templates, clones and recurring series so every spawned mission gets the same
+// tags, objectives and MissionCreated event. Must run inside the caller's transaction
+pub fn spawn_mission<T1, T2, T3>(
+    conn: &mut PgConnection,
+    mission_management_repository: &T1,
+    mission_objective_repository: &T2,
+    outbox_repository: &T3,
+    blueprint: &MissionBlueprint,
+    add_mission_entity: AddMissionEntity,
+) -> Result<i32>
+where
+    T1: MissionManagementRepository,
+    T2: MissionObjectiveRepository,
+    T3: OutboxRepository,
+{
+    let chief_id = add_mission_entity.chief_id;
+    let mission_id = mission_management_repository.add_in_transaction(conn, add_mission_entity)?;
+    mission_management_repository.set_tags_in_transaction(
+        conn,
+        mission_id,
+        blueprint.tags.clone(),
+    )?;
+    mission_objective_repository
+        .add_many_in_transaction(conn, blueprint.to_objective_entities(mission_id))?;
+
+    outbox_repository.stage(
+        conn,
+        vec![DomainEvent::MissionCreated {
+            mission_id,
+            name: blueprint.name.clone(),
+            chief_id,
+        }],
+    )?;
+
+    Ok(mission_id)
+}
+
+fn valid_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.len() < 3 {
+        return Err(anyhow::anyhow!(
+            "Mission name must be least 4 characters long"
+        ));
+    }
+
+    Ok(name.to_string())
+}
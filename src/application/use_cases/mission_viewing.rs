@@ -2,10 +2,14 @@ use std::sync::Arc;
 
 use anyhow::Result;
 
+use crate::config::config_model::AppConfig;
 use crate::domain::{
     repositories::mission_viewing::MissionViewingRepository,
     value_object::{
-        brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_moddel::MissionModel,
+        brawler_model::BrawlerModel,
+        mission_filter::MissionFilter,
+        mission_moddel::{MissionModel, MissionSearchResultModel},
+        pagination::{PaginatedModel, Pagination},
     },
 };
 pub struct MissionViewingUseCase<T>
@@ -13,15 +17,17 @@ where
     T: MissionViewingRepository + Send + Sync,
 {
     mission_viewing_repository: Arc<T>,
+    config: Arc<AppConfig>,
 }
 
 impl<T> MissionViewingUseCase<T>
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    pub fn new(mission_viewing_repository: Arc<T>) -> Self {
+    pub fn new(mission_viewing_repository: Arc<T>, config: Arc<AppConfig>) -> Self {
         Self {
             mission_viewing_repository,
+            config,
         }
     }
 
@@ -42,9 +48,30 @@ where
         Ok(result)
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let result = self.mission_viewing_repository.gets(filter).await?;
+    pub async fn get_all(
+        &self,
+        filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        pagination: &Pagination,
+    ) -> Result<PaginatedModel<MissionSearchResultModel>> {
+        filter.validate()?;
 
-        Ok(result)
+        if filter.not_joined == Some(true) && viewer_id.is_none() {
+            return Err(anyhow::anyhow!(
+                "not_joined requires an authenticated brawler"
+            ));
+        }
+
+        let max_crew_per_mission = self.config.missions.max_crew_per_mission;
+        let missions = self
+            .mission_viewing_repository
+            .gets(filter, viewer_id, max_crew_per_mission, pagination)
+            .await?;
+        let total = self
+            .mission_viewing_repository
+            .counting(filter, viewer_id, max_crew_per_mission)
+            .await?;
+
+        Ok(PaginatedModel::new(missions, pagination, total))
     }
 }
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    repositories::notifications::NotificationRepository,
+    value_object::{
+        notification_model::{NotificationModel, UnreadCountModel},
+        pagination::{PaginatedModel, Pagination},
+    },
+};
+
+pub struct NotificationUseCase<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    notification_repository: Arc<T>,
+}
+
+impl<T> NotificationUseCase<T>
+where
+    T: NotificationRepository + Send + Sync,
+{
+    pub fn new(notification_repository: Arc<T>) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn get_notifications(
+        &self,
+        brawler_id: i32,
+        pagination: &Pagination,
+    ) -> Result<PaginatedModel<NotificationModel>> {
+        let notifications = self
+            .notification_repository
+            .gets(brawler_id, pagination)
+            .await?
+            .into_iter()
+            .map(NotificationModel::from)
+            .collect();
+        let total = self.notification_repository.counting(brawler_id).await?;
+
+        Ok(PaginatedModel::new(notifications, pagination, total))
+    }
+
+    pub async fn get_unread_count(&self, brawler_id: i32) -> Result<UnreadCountModel> {
+        let unread_count = self
+            .notification_repository
+            .unread_counting(brawler_id)
+            .await?;
+
+        Ok(UnreadCountModel { unread_count })
+    }
+
+    pub async fn mark_read(&self, notification_id: i32, brawler_id: i32) -> Result<()> {
+        let updated = self
+            .notification_repository
+            .mark_read(notification_id, brawler_id)
+            .await?;
+
+        if updated == 0 {
+            return Err(anyhow::anyhow!(
+                "Notification not found or already marked as read"
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn mark_all_read(&self, brawler_id: i32) -> Result<usize> {
+        let result = self
+            .notification_repository
+            .mark_all_read(brawler_id)
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    constants::OUTBOX_MAX_ATTEMPTS, repositories::outbox::OutboxRepository,
+    value_object::outbox_model::OutboxLagModel,
+};
+
+pub struct OutboxUseCase<T>
+where
+    T: OutboxRepository + Send + Sync,
+{
+    outbox_repository: Arc<T>,
+}
+
+impl<T> OutboxUseCase<T>
+where
+    T: OutboxRepository + Send + Sync,
+{
+    pub fn new(outbox_repository: Arc<T>) -> Self {
+        Self { outbox_repository }
+    }
+
+    pub async fn get_lag(&self) -> Result<OutboxLagModel> {
+        self.outbox_repository.get_lag(OUTBOX_MAX_ATTEMPTS).await
+    }
+}
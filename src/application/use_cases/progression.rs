@@ -0,0 +1,82 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::progression::AddBrawlerAchievementEntity,
+    repositories::progression::ProgressionRepository,
+    value_object::{
+        achievements::Achievements,
+        progression_model::{AchievementModel, ProgressionModel},
+    },
+};
+
+pub struct ProgressionUseCase<T>
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    progression_repository: Arc<T>,
+}
+
+impl<T> ProgressionUseCase<T>
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    pub fn new(progression_repository: Arc<T>) -> Self {
+        Self {
+            progression_repository,
+        }
+    }
+
+    pub fn get_catalog(&self) -> Vec<AchievementModel> {
+        Achievements::ALL
+            .into_iter()
+            .map(|achievement| AchievementModel::new(achievement, None))
+            .collect()
+    }
+
+    pub async fn get_progress(&self, brawler_id: i32) -> Result<ProgressionModel> {
+        let stats = self.progression_repository.get_stats(brawler_id).await?;
+        let unlocked = self.progression_repository.get_unlocked(brawler_id).await?;
+
+        let achievements = Achievements::ALL
+            .into_iter()
+            .map(|achievement| {
+                let unlocked_at = unlocked
+                    .iter()
+                    .find(|item| item.achievement == achievement.to_string())
+                    .map(|item| item.unlocked_at);
+                AchievementModel::new(achievement, unlocked_at)
+            })
+            .collect();
+
+        Ok(ProgressionModel {
+            brawler_id,
+            xp: stats.xp,
+            level: stats.level,
+            achievements,
+        })
+    }
+}
+
+// Shared by the mission and crew use cases so achievements are evaluated right after the
+// transition that may have earned them
+pub async fn refresh_achievements<T>(progression_repository: &T, brawler_id: i32) -> Result<usize>
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    let stats = progression_repository.get_stats(brawler_id).await?;
+
+    let earned = Achievements::earned(&stats)
+        .into_iter()
+        .map(|achievement| AddBrawlerAchievementEntity {
+            brawler_id,
+            achievement: achievement.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    if earned.is_empty() {
+        return Ok(0);
+    }
+
+    progression_repository.unlock(earned).await
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    constants::{DEFAULT_TAG_SUGGESTIONS, MAX_TAG_SUGGESTIONS},
+    repositories::tags::TagRepository,
+    value_object::tag_model::{TagModel, TagSuggestionFilter, normalize_tag},
+};
+
+pub struct TagUseCase<T>
+where
+    T: TagRepository + Send + Sync,
+{
+    tag_repository: Arc<T>,
+}
+
+impl<T> TagUseCase<T>
+where
+    T: TagRepository + Send + Sync,
+{
+    pub fn new(tag_repository: Arc<T>) -> Self {
+        Self { tag_repository }
+    }
+
+    pub async fn suggest(&self, filter: &TagSuggestionFilter) -> Result<Vec<TagModel>> {
+        // A normalized prefix only holds letters, digits and dashes, so it is safe inside LIKE
+        let prefix = filter
+            .prefix
+            .as_deref()
+            .filter(|prefix| !prefix.trim().is_empty())
+            .map(normalize_tag)
+            .transpose()?;
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_TAG_SUGGESTIONS)
+            .clamp(1, MAX_TAG_SUGGESTIONS);
+
+        self.tag_repository.suggest(prefix, limit).await
+    }
+}
@@ -0,0 +1,131 @@
+use anyhow::Result;
+use rand::Rng;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        constants::MAX_WEBHOOKS_PER_BRAWLER,
+        repositories::webhooks::WebhookRepository,
+        value_object::{
+            pagination::{PaginatedModel, Pagination},
+            webhook_model::{
+                AddWebhookModel, CreatedWebhookModel, WebhookDeliveryModel, WebhookModel,
+            },
+        },
+    },
+    infrastructure::webhooks,
+};
+
+pub struct WebhookUseCase<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    webhook_repository: Arc<T>,
+}
+
+impl<T> WebhookUseCase<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    pub fn new(webhook_repository: Arc<T>) -> Self {
+        Self { webhook_repository }
+    }
+
+    fn generate_secret() -> String {
+        let bytes: [u8; 32] = rand::rng().random();
+        let hex = bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        format!("whsec_{}", hex)
+    }
+
+    pub async fn register(
+        &self,
+        owner_id: i32,
+        add_webhook_model: AddWebhookModel,
+    ) -> Result<CreatedWebhookModel> {
+        let url = add_webhook_model.url.trim();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(anyhow::anyhow!(
+                "Webhook URL must start with http:// or https://"
+            ));
+        }
+        if reqwest::Url::parse(url).is_err() {
+            return Err(anyhow::anyhow!("Webhook URL is not valid"));
+        }
+        webhooks::target::ensure_public_target(url).await?;
+        if add_webhook_model.events.is_empty() {
+            return Err(anyhow::anyhow!("Subscribe to at least one event"));
+        }
+
+        let existing = self.webhook_repository.gets_by_owner(owner_id).await?;
+        if existing.len() >= MAX_WEBHOOKS_PER_BRAWLER {
+            return Err(anyhow::anyhow!(
+                "You can register at most {} webhooks",
+                MAX_WEBHOOKS_PER_BRAWLER
+            ));
+        }
+
+        let webhook = self
+            .webhook_repository
+            .add(add_webhook_model.to_entity(owner_id, Self::generate_secret()))
+            .await?;
+
+        Ok(CreatedWebhookModel {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            secret: webhook.secret,
+        })
+    }
+
+    pub async fn get_webhooks(&self, owner_id: i32) -> Result<Vec<WebhookModel>> {
+        let webhooks = self
+            .webhook_repository
+            .gets_by_owner(owner_id)
+            .await?
+            .into_iter()
+            .map(WebhookModel::from)
+            .collect();
+
+        Ok(webhooks)
+    }
+
+    pub async fn remove(&self, webhook_id: i32, owner_id: i32) -> Result<()> {
+        let removed = self.webhook_repository.remove(webhook_id, owner_id).await?;
+
+        if removed == 0 {
+            return Err(anyhow::anyhow!("Webhook not found"));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        owner_id: i32,
+        pagination: &Pagination,
+    ) -> Result<PaginatedModel<WebhookDeliveryModel>> {
+        let webhook = self.webhook_repository.find_by_id(webhook_id).await?;
+        if webhook.owner_id != owner_id {
+            return Err(anyhow::anyhow!("Webhook not found"));
+        }
+
+        let deliveries = self
+            .webhook_repository
+            .get_deliveries(webhook_id, pagination)
+            .await?
+            .into_iter()
+            .map(WebhookDeliveryModel::from)
+            .collect();
+        let total = self
+            .webhook_repository
+            .counting_deliveries(webhook_id)
+            .await?;
+
+        Ok(PaginatedModel::new(deliveries, pagination, total))
+    }
+}
@@ -0,0 +1,315 @@
+use std::{io::BufRead, sync::Arc};
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use server::{
+    application::use_cases::{
+        brawlers::BrawlersUseCase, maintenance::MaintenanceUseCase,
+        mission_operation::MissionOperationUseCase,
+    },
+    config::{
+        config_loader,
+        config_model::{AppConfig, ImageBackend, RateLimitBackend},
+    },
+    domain::{
+        repositories::brawlers::BrawlerRepository,
+        value_object::{brawler_model::RegisterBrawlerModel, rate_limit_model::RateLimitRule},
+    },
+    infrastructure::database::{
+        migrator,
+        postgresql_connection::{self, PgPoolRunner, PgPoolSquad},
+        repositories::{
+            brawlers::BrawlerPostgres, maintenance::MaintenancePostgres,
+            mission_objectives::MissionObjectivePostgres,
+            mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres,
+            outbox::OutboxPostgres,
+        },
+    },
+};
+
+/// Operational tasks against the same database and config as the server.
+#[derive(Parser)]
+#[command(name = "admin")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Apply pending migrations
+    Migrate,
+    /// Create a brawler; the password is read from stdin when --password is omitted
+    CreateBrawler {
+        username: String,
+        display_name: String,
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// Set a new password for a brawler (id or username)
+    ResetPassword {
+        brawler: String,
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// Change a brawler's display name (id or username)
+    RenameBrawler {
+        brawler: String,
+        display_name: String,
+    },
+    /// Fail an Open or InProgress mission on behalf of its chief
+    FailMission { mission_id: i32 },
+    /// Permanently delete missions and comments soft-deleted more than N days ago
+    PurgeDeleted {
+        #[arg(long, default_value_t = 30)]
+        older_than_days: i64,
+    },
+    /// Print the loaded config (secrets masked) and check the database
+    Config,
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+
+    if let Err(e) = run(cli.command).await {
+        eprintln!("error: {:#}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run(command: Command) -> Result<()> {
+    let config = Arc::new(config_loader::load()?);
+
+    if let Command::Config = command {
+        return print_config(&config).await;
+    }
+
+    let db_pool = Arc::new(postgresql_connection::establish_connection(
+        &config.database,
+    )?);
+
+    match command {
+        Command::Migrate => {
+            let applied = db_pool.run(migrator::run_pending).await?;
+            println!("Applied {} migration(s)", applied.len());
+            for version in applied {
+                println!("  {}", version);
+            }
+        }
+        Command::CreateBrawler {
+            username,
+            display_name,
+            password,
+        } => {
+            let use_case =
+                BrawlersUseCase::new(Arc::new(BrawlerPostgres::new(db_pool)), Arc::clone(&config));
+            let passport = use_case
+                .register(RegisterBrawlerModel {
+                    username,
+                    password: password_or_stdin(password)?,
+                    display_name,
+                    upload_avatar: None,
+                })
+                .await?;
+            println!(
+                "Created brawler {} ({}#{})",
+                passport.brawler_id, passport.display_name, passport.tag
+            );
+        }
+        Command::ResetPassword { brawler, password } => {
+            let repository = Arc::new(BrawlerPostgres::new(db_pool));
+            let brawler_id = resolve_brawler(repository.as_ref(), &brawler).await?;
+            let password = password_or_stdin(password)?;
+
+            BrawlersUseCase::new(repository, Arc::clone(&config))
+                .reset_password(brawler_id, password)
+                .await?;
+            println!("Password reset for brawler {}", brawler_id);
+        }
+        Command::RenameBrawler {
+            brawler,
+            display_name,
+        } => {
+            let repository = Arc::new(BrawlerPostgres::new(db_pool));
+            let brawler_id = resolve_brawler(repository.as_ref(), &brawler).await?;
+
+            BrawlersUseCase::new(repository, Arc::clone(&config))
+                .update_display_name(brawler_id, display_name.clone())
+                .await?;
+            println!("Brawler {} is now {}", brawler_id, display_name);
+        }
+        Command::FailMission { mission_id } => {
+            let use_case = MissionOperationUseCase::new(
+                Arc::new(MissionOperationPostgres::new(Arc::clone(&db_pool))),
+                Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+                Arc::new(MissionObjectivePostgres::new(Arc::clone(&db_pool))),
+                Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+                Arc::clone(&config),
+            );
+            use_case.force_fail(mission_id).await?;
+            println!("Mission {} failed", mission_id);
+        }
+        Command::PurgeDeleted { older_than_days } => {
+            let use_case = MaintenanceUseCase::new(Arc::new(MaintenancePostgres::new(db_pool)));
+            let report = use_case.purge_deleted(older_than_days).await?;
+            println!(
+                "Purged {} mission(s) and {} comment(s) deleted more than {} day(s) ago",
+                report.missions, report.comments, older_than_days
+            );
+        }
+        Command::Config => unreachable!("handled before connecting"),
+    }
+
+    Ok(())
+}
+
+async fn resolve_brawler(repository: &BrawlerPostgres, brawler: &str) -> Result<i32> {
+    if let Ok(brawler_id) = brawler.parse::<i32>() {
+        return Ok(repository.find_by_id(brawler_id).await?.id);
+    }
+
+    let brawler_entity = repository
+        .find_by_username(brawler)
+        .await
+        .with_context(|| format!("No brawler with username {}", brawler))?;
+
+    Ok(brawler_entity.id)
+}
+
+// Keeps passwords out of shell history: `echo -n ... | admin reset-password alice`
+fn password_or_stdin(password: Option<String>) -> Result<String> {
+    let password = match password {
+        Some(password) => password,
+        None => {
+            let mut line = String::new();
+            std::io::stdin().lock().read_line(&mut line)?;
+            line.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+
+    if password.is_empty() {
+        return Err(anyhow::anyhow!("Password cannot be empty"));
+    }
+
+    Ok(password)
+}
+
+fn mask_database_url(url: &str) -> String {
+    match (url.find("://"), url.rfind('@')) {
+        (Some(scheme_end), Some(at)) if at > scheme_end => {
+            let credentials = &url[scheme_end + 3..at];
+            let user = credentials.split(':').next().unwrap_or_default();
+            format!("{}{}:***{}", &url[..scheme_end + 3], user, &url[at..])
+        }
+        _ => url.to_string(),
+    }
+}
+
+fn rate_limit_rule(rule: Option<RateLimitRule>) -> String {
+    match rule {
+        Some(rule) => format!("{}/{}s", rule.requests, rule.period_seconds),
+        None => "off".to_string(),
+    }
+}
+
+async fn print_config(config: &AppConfig) -> Result<()> {
+    println!("stage: {}", config.stage);
+    println!("server.port: {}", config.server.port);
+    println!("server.body_limit: {} MiB", config.server.body_limit);
+    println!("server.timeout: {} s", config.server.timeout);
+    println!(
+        "server.shutdown_timeout: {} s",
+        config.server.shutdown_timeout
+    );
+    println!("database.url: {}", mask_database_url(&config.database.url));
+    println!(
+        "database.max_connections: {}",
+        config.database.max_connections
+    );
+    println!("database.min_idle: {:?}", config.database.min_idle);
+    println!(
+        "database.connection_timeout: {} s",
+        config.database.connection_timeout_seconds
+    );
+    println!(
+        "database.idle_timeout: {} s",
+        config.database.idle_timeout_seconds
+    );
+    println!(
+        "database.statement_timeout: {} ms",
+        config.database.statement_timeout_millis
+    );
+    println!("database.auto_migrate: {}", config.database.auto_migrate);
+    println!("jwt.secret: set ({} chars)", config.jwt.secret.len());
+    println!("jwt.lifetime_days: {}", config.jwt.lift_time_days);
+    println!(
+        "missions.max_crew_per_mission: {}",
+        config.missions.max_crew_per_mission
+    );
+    println!(
+        "server.cors_origins: {}",
+        if config.server.cors_origins.is_empty() {
+            "any".to_string()
+        } else {
+            config.server.cors_origins.join(", ")
+        }
+    );
+    println!(
+        "metrics: {}",
+        match (config.metrics.port, &config.metrics.token) {
+            (Some(port), Some(_)) => format!("port {} (token required)", port),
+            (Some(port), None) => format!("port {}", port),
+            (None, Some(_)) => "api port (token required)".to_string(),
+            (None, None) => "disabled".to_string(),
+        }
+    );
+    println!(
+        "telemetry: {:?} logs, filter {:?}, traces {}",
+        config.telemetry.log_format,
+        config.telemetry.log_filter,
+        config
+            .telemetry
+            .otlp_endpoint
+            .as_deref()
+            .unwrap_or("not exported")
+    );
+    println!(
+        "rate_limit: {}{}, api {}, auth {}, messages {}",
+        match config.rate_limits.backend {
+            RateLimitBackend::Memory => "memory",
+            RateLimitBackend::Postgres => "postgres",
+            RateLimitBackend::Off => "off",
+        },
+        if config.rate_limits.trust_forwarded_for {
+            " (trusting X-Forwarded-For)"
+        } else {
+            ""
+        },
+        rate_limit_rule(config.rate_limits.api),
+        rate_limit_rule(config.rate_limits.auth),
+        rate_limit_rule(config.rate_limits.messages),
+    );
+    println!(
+        "images.backend: {}",
+        match config.images {
+            ImageBackend::Cloudinary(_) => "cloudinary",
+            ImageBackend::Mock => "mock",
+            ImageBackend::Disabled => "none",
+        }
+    );
+
+    let db_pool: PgPoolSquad = match postgresql_connection::establish_connection(&config.database) {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("database: unreachable ({})", e);
+            return Ok(());
+        }
+    };
+    match db_pool.run(migrator::verify).await {
+        Ok(_) => println!("database: reachable, schema up to date"),
+        Err(e) => println!("database: reachable, {}", e),
+    }
+
+    Ok(())
+}
@@ -1,41 +1,352 @@
 use anyhow::Result;
+use tracing_subscriber::EnvFilter;
 
-use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
-    stage::Stage,
+use crate::{
+    config::{
+        config_model::{
+            AppConfig, CloudinaryEnv, Database, ImageBackend, JwtEnv, LogFormat, MetricsEndpoint,
+            MissionRules, RateLimitBackend, RateLimits, Server, Telemetry,
+        },
+        stage::Stage,
+    },
+    domain::{constants::MAX_CREW_PER_MISSION, value_object::rate_limit_model::RateLimitRule},
 };
-use std::str::FromStr;
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    str::FromStr,
+};
+
+// Every setting, the env vars that override it (first set wins) and its default.
+// Later layers win: built-in defaults, config/default.toml, config/<stage>.toml, then env.
+const SETTINGS: &[(&str, &[&str], Option<&str>)] = &[
+    ("server.port", &["SERVER_PORT"], None),
+    ("server.body_limit", &["SERVER_BODY_LIMIT"], Some("10")),
+    ("server.timeout", &["SERVER_TIMEOUT"], Some("30")),
+    (
+        "server.shutdown_timeout",
+        &["SERVER_SHUTDOWN_TIMEOUT"],
+        Some("30"),
+    ),
+    ("server.cors_origins", &["CORS_ORIGINS"], Some("")),
+    ("database.url", &["DATABASE_URL"], None),
+    (
+        "database.max_connections",
+        &["DATABASE_MAX_CONNECTIONS"],
+        Some("10"),
+    ),
+    ("database.min_idle", &["DATABASE_MIN_IDLE"], None),
+    (
+        "database.connection_timeout_seconds",
+        &["DATABASE_CONNECTION_TIMEOUT_SECONDS"],
+        Some("5"),
+    ),
+    (
+        "database.idle_timeout_seconds",
+        &["DATABASE_IDLE_TIMEOUT_SECONDS"],
+        Some("600"),
+    ),
+    (
+        "database.statement_timeout_millis",
+        &["DATABASE_STATEMENT_TIMEOUT_MILLIS"],
+        Some("30000"),
+    ),
+    (
+        "database.auto_migrate",
+        &["DATABASE_AUTO_MIGRATE"],
+        Some("true"),
+    ),
+    ("jwt.secret", &["JWT_SECRET", "JWT_USER_SECRET"], None),
+    (
+        "jwt.lifetime_days",
+        &["JWT_LIFETIME_DAYS", "JTW_LIFTTIME_DAYS"],
+        Some("7"),
+    ),
+    ("images.backend", &["IMAGE_BACKEND"], None),
+    ("cloudinary.cloud_name", &["CLOUDINARY_CLOUD_NAME"], None),
+    ("cloudinary.api_key", &["CLOUDINARY_API_KEY"], None),
+    ("cloudinary.api_secret", &["CLOUDINARY_API_SECRET"], None),
+    (
+        "missions.max_crew_per_mission",
+        &["MAX_CREW_PER_MISSION"],
+        None,
+    ),
+    ("metrics.port", &["METRICS_PORT"], None),
+    ("metrics.token", &["METRICS_TOKEN"], None),
+    ("telemetry.log_format", &["LOG_FORMAT"], None),
+    ("telemetry.log_filter", &["RUST_LOG", "LOG_FILTER"], None),
+    (
+        "telemetry.otlp_endpoint",
+        &["OTEL_EXPORTER_OTLP_ENDPOINT"],
+        None,
+    ),
+    (
+        "telemetry.service_name",
+        &["OTEL_SERVICE_NAME"],
+        Some("crate-server"),
+    ),
+    (
+        "rate_limit.backend",
+        &["RATE_LIMIT_BACKEND"],
+        Some("memory"),
+    ),
+    (
+        "rate_limit.trust_forwarded_for",
+        &["RATE_LIMIT_TRUST_FORWARDED_FOR"],
+        Some("false"),
+    ),
+    ("rate_limit.api", &["RATE_LIMIT_API"], Some("300/60")),
+    ("rate_limit.auth", &["RATE_LIMIT_AUTH"], Some("10/60")),
+    (
+        "rate_limit.messages",
+        &["RATE_LIMIT_MESSAGES"],
+        Some("30/60"),
+    ),
+];
 
-pub fn load() -> Result<DotEnvyConfig> {
+// Loads and validates the whole config once; every problem is reported in a single error
+pub fn load() -> Result<AppConfig> {
     dotenvy::dotenv().ok();
 
+    let stage = get_stage();
+    let config_dir = std::env::var("CONFIG_DIR").unwrap_or_else(|_| "config".to_string());
+    let mut layers = Layers::new();
+
+    if let Ok(raw) = std::env::var("STAGE")
+        && !raw.is_empty()
+        && Stage::from_str(&raw).is_err()
+    {
+        layers.error("STAGE", "must be Local, Dev or Prod");
+    }
+
+    layers.read_file(&Path::new(&config_dir).join("default.toml"));
+    layers.read_file(&Path::new(&config_dir).join(format!("{}.toml", stage_file(&stage))));
+    layers.read_env();
+
     let server = Server {
-        port: std::env::var("SERVER_PORT")
-            .expect("SERVER_PORT is valid")
-            .parse()?,
-        body_limit: std::env::var("SERVER_BODY_LIMIT")
-            .expect("SERVER_BODY_LIMIT is valid")
-            .parse()?,
-        timeout: std::env::var("SERVER_TIMEOUT")
-            .expect("SERVER_TIMEOUT is valid")
-            .parse()?,
+        port: layers.check(
+            "server.port",
+            |port: &u16| *port > 0,
+            "must be greater than 0",
+        ),
+        body_limit: layers.check(
+            "server.body_limit",
+            |mb: &u64| *mb > 0,
+            "must be greater than 0",
+        ),
+        timeout: layers.check(
+            "server.timeout",
+            |secs: &u64| *secs > 0,
+            "must be greater than 0",
+        ),
+        shutdown_timeout: layers.parsed("server.shutdown_timeout"),
+        cors_origins: layers
+            .parsed::<String>("server.cors_origins")
+            .split(',')
+            .map(|origin| origin.trim().trim_end_matches('/').to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect(),
     };
+    for origin in &server.cors_origins {
+        if !(origin.starts_with("http://") || origin.starts_with("https://")) {
+            layers.error(
+                "server.cors_origins",
+                &format!("{:?} must be an http(s):// origin", origin),
+            );
+        }
+    }
+    if stage == Stage::Production && server.cors_origins.is_empty() {
+        layers.error(
+            "server.cors_origins",
+            "must list the allowed origins in Production",
+        );
+    }
 
     let database = Database {
-        url: std::env::var("DATABASE_URL").expect("DATABASE_URL not set"),
+        url: layers.check(
+            "database.url",
+            |url: &String| url.starts_with("postgres://") || url.starts_with("postgresql://"),
+            "must be a postgres:// URL",
+        ),
+        max_connections: layers.check(
+            "database.max_connections",
+            |size: &u32| *size > 0,
+            "must be greater than 0",
+        ),
+        min_idle: layers.optional("database.min_idle"),
+        connection_timeout_seconds: layers.check(
+            "database.connection_timeout_seconds",
+            |secs: &u64| *secs > 0,
+            "must be greater than 0",
+        ),
+        idle_timeout_seconds: layers.parsed("database.idle_timeout_seconds"),
+        statement_timeout_millis: layers.parsed("database.statement_timeout_millis"),
+        auto_migrate: layers.parsed("database.auto_migrate"),
     };
+    if let Some(min_idle) = database.min_idle
+        && min_idle > database.max_connections
+    {
+        layers.error(
+            "database.min_idle",
+            "cannot exceed database.max_connections",
+        );
+    }
 
-    let secret = std::env::var("JWT_SECRET")
-        .or_else(|_| std::env::var("JWT_USER_SECRET"))
-        .expect("JWT_SECRET or JWT_USER_SECRET not set in .env");
+    let jwt = JwtEnv {
+        secret: layers.check(
+            "jwt.secret",
+            |secret: &String| !secret.is_empty(),
+            "cannot be empty",
+        ),
+        lift_time_days: layers.check(
+            "jwt.lifetime_days",
+            |days: &i64| *days > 0,
+            "must be greater than 0",
+        ),
+    };
+    if stage == Stage::Production && !is_strong_secret(&jwt.secret) {
+        layers.error(
+            "jwt.secret",
+            &format!(
+                "must be at least {} characters and not repetitive in Production",
+                MIN_PRODUCTION_SECRET_LEN
+            ),
+        );
+    }
 
-    let config = DotEnvyConfig {
-        server,
-        database,
-        secret,
+    let cloudinary = match (
+        layers.optional::<String>("cloudinary.cloud_name"),
+        layers.optional::<String>("cloudinary.api_key"),
+        layers.optional::<String>("cloudinary.api_secret"),
+    ) {
+        (Some(cloud_name), Some(api_key), Some(api_secret)) => Some(CloudinaryEnv {
+            cloud_name,
+            api_key,
+            api_secret,
+        }),
+        (None, None, None) => None,
+        _ => {
+            layers.error(
+                "cloudinary",
+                "cloud_name, api_key and api_secret must be set together",
+            );
+            None
+        }
+    };
+
+    // Without an explicit backend, uploads go to Cloudinary when it is configured
+    let images = match (
+        layers.optional::<String>("images.backend").as_deref(),
+        cloudinary,
+    ) {
+        (None | Some("cloudinary"), Some(cloudinary)) => ImageBackend::Cloudinary(cloudinary),
+        (None | Some("none"), _) => ImageBackend::Disabled,
+        (Some("cloudinary"), None) => {
+            layers.error(
+                "images.backend",
+                "cloudinary requires the cloudinary settings",
+            );
+            ImageBackend::Disabled
+        }
+        (Some("mock"), _) if stage == Stage::Local => ImageBackend::Mock,
+        (Some("mock"), _) => {
+            layers.error("images.backend", "mock is only allowed in Local");
+            ImageBackend::Disabled
+        }
+        (Some(other), _) => {
+            layers.error(
+                "images.backend",
+                &format!("{:?} must be cloudinary, mock or none", other),
+            );
+            ImageBackend::Disabled
+        }
+    };
+
+    let missions = MissionRules {
+        max_crew_per_mission: layers
+            .optional("missions.max_crew_per_mission")
+            .unwrap_or(MAX_CREW_PER_MISSION),
+    };
+    if missions.max_crew_per_mission == 0 {
+        layers.error("missions.max_crew_per_mission", "must be greater than 0");
+    }
+
+    let metrics = MetricsEndpoint {
+        port: layers.optional("metrics.port"),
+        token: layers
+            .optional::<String>("metrics.token")
+            .filter(|token| !token.is_empty()),
     };
+    if metrics.port.is_some() && metrics.port == Some(server.port) {
+        layers.error("metrics.port", "must differ from server.port");
+    }
 
-    Ok(config)
+    // Production defaults to JSON at info for the log collector; other stages stay readable at debug
+    let production = stage == Stage::Production;
+    let telemetry = Telemetry {
+        log_format: match layers.optional::<String>("telemetry.log_format").as_deref() {
+            None if production => LogFormat::Json,
+            None => LogFormat::Text,
+            Some("json") => LogFormat::Json,
+            Some("text") => LogFormat::Text,
+            Some(other) => {
+                layers.error(
+                    "telemetry.log_format",
+                    &format!("{:?} must be json or text", other),
+                );
+                LogFormat::Text
+            }
+        },
+        log_filter: layers
+            .optional("telemetry.log_filter")
+            .unwrap_or_else(|| if production { "info" } else { "debug" }.to_string()),
+        otlp_endpoint: layers
+            .optional::<String>("telemetry.otlp_endpoint")
+            .filter(|endpoint| !endpoint.is_empty())
+            .map(|endpoint| endpoint.trim_end_matches('/').to_string()),
+        service_name: layers.parsed("telemetry.service_name"),
+    };
+    if let Err(e) = EnvFilter::try_new(&telemetry.log_filter) {
+        layers.error("telemetry.log_filter", &e.to_string());
+    }
+    if let Some(endpoint) = &telemetry.otlp_endpoint
+        && !(endpoint.starts_with("http://") || endpoint.starts_with("https://"))
+    {
+        layers.error("telemetry.otlp_endpoint", "must be an http(s):// URL");
+    }
+
+    let rate_limits = RateLimits {
+        backend: match layers.parsed::<String>("rate_limit.backend").as_str() {
+            "memory" => RateLimitBackend::Memory,
+            "postgres" => RateLimitBackend::Postgres,
+            "off" => RateLimitBackend::Off,
+            other => {
+                layers.error(
+                    "rate_limit.backend",
+                    &format!("{:?} must be memory, postgres or off", other),
+                );
+                RateLimitBackend::Off
+            }
+        },
+        trust_forwarded_for: layers.parsed("rate_limit.trust_forwarded_for"),
+        api: layers.rate_limit_rule("rate_limit.api"),
+        auth: layers.rate_limit_rule("rate_limit.auth"),
+        messages: layers.rate_limit_rule("rate_limit.messages"),
+    };
+
+    layers.finish()?;
+
+    Ok(AppConfig {
+        stage,
+        server,
+        database,
+        jwt,
+        images,
+        missions,
+        metrics,
+        telemetry,
+        rate_limits,
+    })
 }
 
 pub fn get_stage() -> Stage {
@@ -46,33 +357,171 @@ pub fn get_stage() -> Stage {
     Stage::from_str(&stage_str).unwrap_or_default()
 }
 
-pub fn get_user_secret() -> Result<String> {
-    dotenvy::dotenv().ok();
-    let secret_env = std::env::var("JWT_SECRET")
-        .or_else(|_| std::env::var("JWT_USER_SECRET"))
-        .map_err(|_| anyhow::anyhow!("JWT_SECRET or JWT_USER_SECRET not set"))?;
-    Ok(secret_env)
+const MIN_PRODUCTION_SECRET_LEN: usize = 32;
+
+fn is_strong_secret(secret: &str) -> bool {
+    let distinct = secret.chars().collect::<HashSet<_>>().len();
+
+    secret.len() >= MIN_PRODUCTION_SECRET_LEN && distinct >= 8
 }
 
-// pub fn get_user_secret_env() -> Result<JwtEnv> {
-pub fn get_jwt_env() -> Result<JwtEnv> {
-    dotenvy::dotenv().ok();
+fn stage_file(stage: &Stage) -> &'static str {
+    match stage {
+        Stage::Local => "local",
+        Stage::Development => "development",
+        Stage::Production => "production",
+    }
+}
 
-    Ok(JwtEnv {
-        secret: std::env::var("JWT_USER_SECRET").or_else(|_| std::env::var("JWT_SECRET"))?,
-        lift_time_days: std::env::var("JWT_LIFETIME_DAYS")
-            .or_else(|_| std::env::var("JTW_LIFTTIME_DAYS"))
-            .unwrap_or_else(|_| "7".to_string()) // Default to 7 days
-            .parse::<i64>()?,
-    })
+struct Layers {
+    // key -> (raw value, where it came from)
+    values: HashMap<String, (String, String)>,
+    errors: Vec<String>,
 }
 
-pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
-    dotenvy::dotenv().ok();
+impl Layers {
+    fn new() -> Self {
+        let values = SETTINGS
+            .iter()
+            .filter_map(|(key, _, default)| {
+                default.map(|value| (key.to_string(), (value.to_string(), "default".to_string())))
+            })
+            .collect();
 
-    Ok(CloudinaryEnv {
-        cloud_name: std::env::var("CLOUDINARY_CLOUD_NAME")?,
-        api_key: std::env::var("CLOUDINARY_API_KEY")?,
-        api_secret: std::env::var("CLOUDINARY_API_SECRET")?,
-    })
+        Self {
+            values,
+            errors: Vec::new(),
+        }
+    }
+
+    // A missing file is fine; a malformed one is an error like any other
+    fn read_file(&mut self, path: &Path) {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return;
+        };
+        let source = path.display().to_string();
+
+        let table = match content.parse::<toml::Table>() {
+            Ok(table) => table,
+            Err(e) => {
+                self.errors.push(format!("{}: {}", source, e.message()));
+                return;
+            }
+        };
+
+        for (section, entries) in table {
+            let Some(entries) = entries.as_table() else {
+                self.errors
+                    .push(format!("{}: `{}` must be a [section]", source, section));
+                continue;
+            };
+            for (name, value) in entries {
+                let key = format!("{}.{}", section, name);
+                if !SETTINGS.iter().any(|(known, _, _)| *known == key) {
+                    self.errors
+                        .push(format!("{}: unknown setting `{}`", source, key));
+                    continue;
+                }
+                let raw = match value {
+                    toml::Value::String(value) => value.clone(),
+                    other => other.to_string(),
+                };
+                self.values.insert(key, (raw, source.clone()));
+            }
+        }
+    }
+
+    fn read_env(&mut self) {
+        for (key, vars, _) in SETTINGS {
+            if let Some((var, value)) = vars
+                .iter()
+                .find_map(|var| std::env::var(var).ok().map(|value| (var, value)))
+            {
+                self.values
+                    .insert(key.to_string(), (value, var.to_string()));
+            }
+        }
+    }
+
+    fn error(&mut self, key: &str, message: &str) {
+        self.errors.push(format!("{}: {}", key, message));
+    }
+
+    fn optional<T: FromStr>(&mut self, key: &str) -> Option<T>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let (raw, source) = self.values.get(key)?;
+        match raw.parse::<T>() {
+            Ok(value) => Some(value),
+            Err(e) => {
+                let message = format!("{}: invalid value {:?} from {} ({})", key, raw, source, e);
+                self.errors.push(message);
+                None
+            }
+        }
+    }
+
+    fn parsed<T: FromStr + Default>(&mut self, key: &str) -> T
+    where
+        T::Err: std::fmt::Display,
+    {
+        if !self.values.contains_key(key) {
+            let vars = SETTINGS
+                .iter()
+                .find(|(known, _, _)| *known == key)
+                .map(|(_, vars, _)| vars.join(" or "))
+                .unwrap_or_default();
+            self.errors.push(format!(
+                "{}: missing (set {} or add it to a config file)",
+                key, vars
+            ));
+            return T::default();
+        }
+
+        self.optional(key).unwrap_or_default()
+    }
+
+    fn check<T: FromStr + Default>(
+        &mut self,
+        key: &str,
+        valid: impl Fn(&T) -> bool,
+        message: &str,
+    ) -> T
+    where
+        T::Err: std::fmt::Display,
+    {
+        let errors_before = self.errors.len();
+        let value = self.parsed::<T>(key);
+        if self.errors.len() == errors_before && !valid(&value) {
+            self.error(key, message);
+        }
+
+        value
+    }
+
+    // `requests/seconds`, or `off` to leave the group unlimited
+    fn rate_limit_rule(&mut self, key: &str) -> Option<RateLimitRule> {
+        match self.optional::<String>(key)?.as_str() {
+            "off" => None,
+            raw => match raw.parse() {
+                Ok(rule) => Some(rule),
+                Err(e) => {
+                    self.error(key, &format!("invalid value {:?} ({})", raw, e));
+                    None
+                }
+            },
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(anyhow::anyhow!(
+            "Invalid configuration:\n  - {}",
+            self.errors.join("\n  - ")
+        ))
+    }
 }
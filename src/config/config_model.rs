@@ -1,21 +1,40 @@
+use super::stage::Stage;
+use crate::domain::value_object::rate_limit_model::RateLimitRule;
+
 #[derive(Debug, Clone)]
 pub struct Server {
     pub port: u16,
     pub body_limit: u64,
     pub timeout: u64,
+    // Seconds in-flight requests, then background workers, get to finish after SIGTERM
+    pub shutdown_timeout: u64,
+    // Empty allows any origin; Production refuses to start without an explicit list
+    pub cors_origins: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Database {
     pub url: String,
+    pub max_connections: u32,
+    pub min_idle: Option<u32>,
+    pub connection_timeout_seconds: u64,
+    pub idle_timeout_seconds: u64,
+    // 0 disables the per-statement limit
+    pub statement_timeout_millis: u64,
+    pub auto_migrate: bool,
 }
 
 #[derive(Debug, Clone)]
-pub struct DotEnvyConfig {
+pub struct AppConfig {
+    pub stage: Stage,
     pub server: Server,
     pub database: Database,
-    pub secret: String,
-    // pub max_crew_per_mission: u32,
+    pub jwt: JwtEnv,
+    pub images: ImageBackend,
+    pub missions: MissionRules,
+    pub metrics: MetricsEndpoint,
+    pub telemetry: Telemetry,
+    pub rate_limits: RateLimits,
 }
 
 #[derive(Debug, Clone)]
@@ -30,4 +49,62 @@ pub struct CloudinaryEnv {
     pub cloud_name: String,
     pub api_key: String,
     pub api_secret: String,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone)]
+pub enum ImageBackend {
+    Cloudinary(CloudinaryEnv),
+    // Local only: keeps the image inline as a data URL instead of uploading it
+    Mock,
+    // Avatar uploads are rejected
+    Disabled,
+}
+
+#[derive(Debug, Clone)]
+pub struct MissionRules {
+    pub max_crew_per_mission: u32,
+}
+
+// /metrics is served on its own port when one is set, otherwise on the API port behind the token;
+// with neither it is not exposed at all
+#[derive(Debug, Clone)]
+pub struct MetricsEndpoint {
+    pub port: Option<u16>,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogFormat {
+    Json,
+    Text,
+}
+
+#[derive(Debug, Clone)]
+pub struct Telemetry {
+    pub log_format: LogFormat,
+    // An EnvFilter directive such as `info,server=debug`
+    pub log_filter: String,
+    // Base URL of an OTLP/HTTP collector; traces are only exported when it is set
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitBackend {
+    // Per process: each instance behind a load balancer counts on its own
+    Memory,
+    // Shared by every instance through the rate_limit_buckets table
+    Postgres,
+    Off,
+}
+
+// A group set to `None` is not limited
+#[derive(Debug, Clone)]
+pub struct RateLimits {
+    pub backend: RateLimitBackend,
+    // Only behind a proxy that sets X-Forwarded-For, otherwise clients can pick their own key
+    pub trust_forwarded_for: bool,
+    pub api: Option<RateLimitRule>,
+    pub auth: Option<RateLimitRule>,
+    pub messages: Option<RateLimitRule>,
+}
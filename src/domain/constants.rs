@@ -1 +1,32 @@
 pub const MAX_CREW_PER_MISSION: u32 = 5;
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 100;
+pub const MAX_PAGE: i64 = 10_000;
+pub const MAX_COMMENT_LENGTH: usize = 2000;
+pub const MAX_OBJECTIVE_TITLE_LENGTH: usize = 255;
+pub const RATING_WINDOW_DAYS: i64 = 7;
+pub const ABANDONMENT_WINDOW_HOURS: i64 = 24;
+pub const MISSION_COMPLETION_BASE_XP: i32 = 100;
+pub const MISSION_COMPLETION_XP_PER_CREW: i32 = 25;
+pub const MISSION_CHIEF_BONUS_XP: i32 = 50;
+pub const WEBHOOK_MAX_ATTEMPTS: i32 = 8;
+pub const WEBHOOK_RETRY_BASE_SECONDS: i64 = 30;
+pub const WEBHOOK_BATCH_SIZE: i64 = 20;
+pub const WEBHOOK_LEASE_SECONDS: i64 = 60;
+pub const WEBHOOK_POLL_INTERVAL_SECONDS: u64 = 5;
+pub const WEBHOOK_TIMEOUT_SECONDS: u64 = 10;
+pub const MAX_WEBHOOKS_PER_BRAWLER: usize = 10;
+pub const OUTBOX_BATCH_SIZE: i64 = 50;
+pub const OUTBOX_LEASE_SECONDS: i64 = 30;
+pub const OUTBOX_MAX_ATTEMPTS: i32 = 10;
+pub const OUTBOX_RETRY_BASE_SECONDS: i64 = 5;
+pub const OUTBOX_POLL_INTERVAL_MILLIS: u64 = 500;
+pub const MAX_SEARCH_TERMS: usize = 8;
+pub const MAX_TAGS_PER_MISSION: usize = 10;
+pub const MAX_TAG_LENGTH: usize = 32;
+pub const DEFAULT_TAG_SUGGESTIONS: i64 = 10;
+pub const MAX_TAG_SUGGESTIONS: i64 = 50;
+pub const MAX_TEMPLATES_PER_BRAWLER: usize = 50;
+pub const MISSION_SCHEDULER_INTERVAL_SECONDS: u64 = 30;
+pub const MISSION_SCHEDULER_BATCH_SIZE: i64 = 50;
+pub const CALENDAR_DEFAULT_EVENT_MINUTES: i64 = 60;
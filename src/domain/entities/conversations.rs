@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database::schema::{conversation_members, conversations};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize, Deserialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = conversations)]
+pub struct ConversationEntity {
+    pub id: i32,
+    pub mission_id: Option<i32>,
+    pub name: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = conversations)]
+pub struct AddConversationEntity {
+    pub mission_id: Option<i32>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = conversation_members)]
+pub struct ConversationMemberEntity {
+    pub conversation_id: i32,
+    pub brawler_id: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConversationMessage {
+    pub id: i32,
+    pub conversation_id: i32,
+    pub sender_id: i32,
+    pub content: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,31 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_comments;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_comments)]
+pub struct MissionCommentEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub content: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_comments)]
+pub struct AddMissionCommentEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_comments)]
+pub struct EditMissionCommentEntity {
+    pub content: String,
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_objectives;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_objectives)]
+pub struct MissionObjectiveEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub is_mandatory: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_objectives)]
+pub struct AddMissionObjectiveEntity {
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub is_mandatory: bool,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_objectives)]
+pub struct EditMissionObjectiveEntity {
+    pub position: Option<i32>,
+    pub title: Option<String>,
+    pub is_mandatory: Option<bool>,
+}
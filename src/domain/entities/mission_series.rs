@@ -0,0 +1,76 @@
+use anyhow::Result;
+use chrono::{NaiveDateTime, Weekday};
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_object::recurrence::{MissionSeriesModel, RecurrenceRule},
+    infrastructure::database::schema::mission_series,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, QueryableByName)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_series)]
+pub struct MissionSeriesEntity {
+    pub id: i32,
+    pub chief_id: i32,
+    pub frequency: String,
+    pub weekdays: Vec<i32>,
+    pub starts_at: NaiveDateTime,
+    pub next_occurrence_at: NaiveDateTime,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionSeriesEntity {
+    pub fn rule(&self) -> Result<RecurrenceRule> {
+        let weekdays = self
+            .weekdays
+            .iter()
+            .map(|day| Ok(Weekday::try_from(u8::try_from(*day)?)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RecurrenceRule {
+            frequency: self.frequency.parse()?,
+            weekdays,
+            starts_at: self.starts_at,
+        })
+    }
+
+    pub fn to_model(&self) -> Result<MissionSeriesModel> {
+        Ok(MissionSeriesModel {
+            id: self.id,
+            chief_id: self.chief_id,
+            rule: self.rule()?,
+            next_occurrence_at: self.next_occurrence_at,
+            is_active: self.is_active,
+            created_at: self.created_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_series)]
+pub struct AddMissionSeriesEntity {
+    pub chief_id: i32,
+    pub frequency: String,
+    pub weekdays: Vec<i32>,
+    pub starts_at: NaiveDateTime,
+    pub next_occurrence_at: NaiveDateTime,
+}
+
+impl AddMissionSeriesEntity {
+    pub fn new(chief_id: i32, rule: &RecurrenceRule, next_occurrence_at: NaiveDateTime) -> Self {
+        Self {
+            chief_id,
+            frequency: rule.frequency.to_string(),
+            weekdays: rule
+                .weekdays
+                .iter()
+                .map(|day| day.num_days_from_monday() as i32)
+                .collect(),
+            starts_at: rule.starts_at,
+            next_occurrence_at,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_object::mission_template_model::{
+        MissionBlueprint, MissionTemplateModel, TemplateObjectiveModel,
+    },
+    infrastructure::database::schema::mission_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_templates)]
+pub struct MissionTemplateEntity {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub comment_visibility: String,
+    pub require_objectives: bool,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub objectives: serde_json::Value,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionTemplateEntity {
+    pub fn to_blueprint(&self) -> MissionBlueprint {
+        MissionBlueprint {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            comment_visibility: self.comment_visibility.clone(),
+            require_objectives: self.require_objectives,
+            category: self.category.clone(),
+            tags: self.tags.clone(),
+            objectives: serde_json::from_value::<Vec<TemplateObjectiveModel>>(
+                self.objectives.clone(),
+            )
+            .unwrap_or_default(),
+        }
+    }
+
+    pub fn to_model(&self) -> MissionTemplateModel {
+        MissionTemplateModel {
+            id: self.id,
+            blueprint: self.to_blueprint(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_templates)]
+pub struct AddMissionTemplateEntity {
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub comment_visibility: String,
+    pub require_objectives: bool,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub objectives: serde_json::Value,
+}
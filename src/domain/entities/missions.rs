@@ -17,10 +17,23 @@ pub struct MissionEntity {
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
+    pub comment_visibility: String,
+    pub require_objectives: bool,
+    pub finished_at: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
+    pub category: Option<String>,
+    pub series_id: Option<i32>,
+    pub scheduled_for: Option<NaiveDateTime>,
 }
 
 impl MissionEntity {
-    pub fn to_model(&self, crew_count: i64, chief_display_name: String) -> MissionModel {
+    pub fn to_model(
+        &self,
+        crew_count: i64,
+        chief_display_name: String,
+        completion_percentage: f64,
+        tags: Vec<String>,
+    ) -> MissionModel {
         MissionModel {
             id: self.id,
             name: self.name.clone(),
@@ -29,8 +42,17 @@ impl MissionEntity {
             chief_id: self.chief_id,
             chief_display_name,
             crew_count,
+            comment_visibility: self.comment_visibility.clone(),
+            require_objectives: self.require_objectives,
+            completion_percentage,
             created_at: self.created_at,
             updated_at: self.updated_at,
+            finished_at: self.finished_at,
+            deadline: self.deadline,
+            category: self.category.clone(),
+            tags,
+            series_id: self.series_id,
+            scheduled_for: self.scheduled_for,
         }
     }
 }
@@ -42,6 +64,12 @@ pub struct AddMissionEntity {
     pub name: String,
     pub status: String,
     pub description: Option<String>,
+    pub comment_visibility: String,
+    pub require_objectives: bool,
+    pub deadline: Option<NaiveDateTime>,
+    pub category: Option<String>,
+    pub series_id: Option<i32>,
+    pub scheduled_for: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
@@ -51,4 +79,8 @@ pub struct EditMissionEntity {
     pub name: Option<String>,
     pub description: Option<String>,
     pub(crate) status: Option<String>,
+    pub comment_visibility: Option<String>,
+    pub require_objectives: Option<bool>,
+    pub deadline: Option<NaiveDateTime>,
+    pub category: Option<String>,
 }
@@ -1,5 +1,15 @@
 pub mod brawlers;
+pub mod conversations;
 pub mod crew_memberships;
 pub mod friendships;
 pub mod messages;
+pub mod mission_comments;
+pub mod mission_objectives;
+pub mod mission_ratings;
+pub mod mission_series;
+pub mod mission_templates;
 pub mod missions;
+pub mod notifications;
+pub mod outbox;
+pub mod webhooks;
+pub mod progression;
@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_object::notification_kinds::NotificationKinds,
+    infrastructure::database::schema::notifications,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = notifications)]
+pub struct NotificationEntity {
+    pub id: i32,
+    pub recipient_id: i32,
+    pub actor_id: Option<i32>,
+    pub mission_id: Option<i32>,
+    pub kind: String,
+    pub content: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = notifications)]
+pub struct AddNotificationEntity {
+    pub recipient_id: i32,
+    pub actor_id: Option<i32>,
+    pub mission_id: Option<i32>,
+    pub kind: String,
+    pub content: String,
+}
+
+impl AddNotificationEntity {
+    pub fn new(
+        recipient_id: i32,
+        actor_id: Option<i32>,
+        mission_id: Option<i32>,
+        kind: NotificationKinds,
+        content: String,
+    ) -> Self {
+        Self {
+            recipient_id,
+            actor_id,
+            mission_id,
+            kind: kind.to_string(),
+            content,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{domain::events::DomainEvent, infrastructure::database::schema::outbox};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, QueryableByName)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = outbox)]
+pub struct OutboxEntity {
+    pub id: i64,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub handled_by: Vec<String>,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub processed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = outbox)]
+pub struct AddOutboxEntity {
+    pub event_type: String,
+    pub payload: serde_json::Value,
+}
+
+impl AddOutboxEntity {
+    pub fn from_event(event: &DomainEvent) -> Result<Self> {
+        Ok(Self {
+            event_type: event.name().to_string(),
+            payload: serde_json::to_value(event)?,
+        })
+    }
+}
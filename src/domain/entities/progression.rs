@@ -0,0 +1,19 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{brawler_achievements, xp_awards};
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = xp_awards)]
+pub struct AddXpAwardEntity {
+    pub brawler_id: i32,
+    pub mission_id: i32,
+    pub amount: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct AddBrawlerAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement: String,
+}
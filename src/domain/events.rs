@@ -0,0 +1,63 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_object::mission_statuses::MissionStatuses;
+
+// Facts recorded by use cases in the same transaction as their changes. Each variant carries
+// enough context for subscribers to react without reading the state back
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum DomainEvent {
+    MissionCreated {
+        mission_id: i32,
+        name: String,
+        chief_id: i32,
+    },
+    MissionStatusChanged {
+        mission_id: i32,
+        name: String,
+        chief_id: i32,
+        status: MissionStatuses,
+        crew_ids: Vec<i32>,
+    },
+    CrewJoined {
+        mission_id: i32,
+        mission_name: String,
+        chief_id: i32,
+        brawler_id: i32,
+    },
+    CrewLeft {
+        mission_id: i32,
+        mission_name: String,
+        chief_id: i32,
+        brawler_id: i32,
+    },
+    FriendAdded {
+        brawler_id: i32,
+        friend_id: i32,
+    },
+    MessageSent {
+        message_id: i32,
+        sender_id: i32,
+        receiver_id: i32,
+    },
+}
+
+impl DomainEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::MissionCreated { .. } => "MissionCreated",
+            DomainEvent::MissionStatusChanged { .. } => "MissionStatusChanged",
+            DomainEvent::CrewJoined { .. } => "CrewJoined",
+            DomainEvent::CrewLeft { .. } => "CrewLeft",
+            DomainEvent::FriendAdded { .. } => "FriendAdded",
+            DomainEvent::MessageSent { .. } => "MessageSent",
+        }
+    }
+}
+
+#[async_trait]
+pub trait EventSubscriber {
+    fn name(&self) -> &'static str;
+    async fn handle(&self, event: &DomainEvent) -> Result<()>;
+}
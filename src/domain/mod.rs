@@ -5,3 +5,5 @@ pub mod value_object;
 pub mod repositories;
 
 pub mod constants;
+
+pub mod events;
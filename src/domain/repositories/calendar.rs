@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::value_object::mission_moddel::MissionModel;
+
+#[async_trait]
+#[automock]
+pub trait CalendarRepository {
+    async fn save_token_hash(&self, brawler_id: i32, token_hash: String) -> Result<()>;
+    async fn revoke_token(&self, brawler_id: i32) -> Result<()>;
+    async fn find_brawler_by_token_hash(&self, token_hash: String) -> Result<Option<i32>>;
+    // Missions the brawler leads or crews in
+    async fn missions_of(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::conversations::{ConversationEntity, ConversationMessage},
+    value_object::conversation_model::ConversationSummaryModel,
+};
+
+#[async_trait]
+#[automock]
+pub trait ConversationRepository {
+    async fn create_mission_channel(
+        &self,
+        mission_id: i32,
+        name: String,
+        chief_id: i32,
+    ) -> Result<i32>;
+    async fn find_by_id(&self, conversation_id: i32) -> Result<ConversationEntity>;
+    async fn find_by_mission(&self, mission_id: i32) -> Result<Option<ConversationEntity>>;
+    // Adds or removes the brawler to match their current crew_memberships row, so a retried or
+    // reordered CrewJoined/CrewLeft ends in the same state
+    async fn sync_crew_member(
+        &self,
+        conversation_id: i32,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<()>;
+    async fn is_member(&self, conversation_id: i32, brawler_id: i32) -> Result<bool>;
+    async fn get_channels(&self, brawler_id: i32) -> Result<Vec<ConversationSummaryModel>>;
+    async fn send_message(
+        &self,
+        conversation_id: i32,
+        sender_id: i32,
+        content: String,
+    ) -> Result<ConversationMessage>;
+    async fn get_messages(&self, conversation_id: i32) -> Result<Vec<ConversationMessage>>;
+    async fn mark_as_read(&self, conversation_id: i32, brawler_id: i32) -> Result<()>;
+}
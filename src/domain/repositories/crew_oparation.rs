@@ -9,6 +9,16 @@ use mockall::automock;
 pub trait CrewOperationRepository {
     async fn join(&self, crew_memberships: CrewMemberShips) -> Result<()>;
     async fn leave(&self, crew_memberships: CrewMemberShips) -> Result<()>;
+    fn join_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        crew_memberships: CrewMemberShips,
+    ) -> Result<()>;
+    fn leave_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        crew_memberships: CrewMemberShips,
+    ) -> Result<()>;
 
     fn for_insert_transaction_test(
         &self,
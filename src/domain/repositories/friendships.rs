@@ -2,10 +2,16 @@ use crate::domain::entities::brawlers::BrawlerEntity;
 use crate::domain::entities::friendships::{FriendshipEntity, NewFriendshipEntity};
 use anyhow::Result;
 use async_trait::async_trait;
+use diesel::PgConnection;
 
 #[async_trait]
 pub trait FriendshipRepository {
     async fn add_friend(&self, friendship: NewFriendshipEntity) -> Result<()>;
+    fn add_friend_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        friendship: NewFriendshipEntity,
+    ) -> Result<()>;
     async fn find_friendship(
         &self,
         brawler_id: i32,
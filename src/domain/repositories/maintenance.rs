@@ -0,0 +1,13 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::value_object::maintenance_model::PurgeReportModel;
+
+#[async_trait]
+#[automock]
+pub trait MaintenanceRepository {
+    // Hard-deletes missions and comments soft-deleted before `deleted_before`, along with their dependents
+    async fn purge_deleted(&self, deleted_before: NaiveDateTime) -> Result<PurgeReportModel>;
+}
@@ -1,6 +1,7 @@
 use crate::domain::entities::messages::Message;
 use anyhow::Result;
 use async_trait::async_trait;
+use diesel::PgConnection;
 
 #[async_trait]
 pub trait MessageRepository: Send + Sync {
@@ -10,6 +11,13 @@ pub trait MessageRepository: Send + Sync {
         receiver_id: i32,
         content: String,
     ) -> Result<Message>;
+    fn send_message_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        sender_id: i32,
+        receiver_id: i32,
+        content: String,
+    ) -> Result<Message>;
     async fn get_conversation(&self, brawler_id: i32, friend_id: i32) -> Result<Vec<Message>>;
     async fn mark_as_read(&self, receiver_id: i32, sender_id: i32) -> Result<()>;
 }
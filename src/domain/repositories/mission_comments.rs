@@ -0,0 +1,30 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::mission_comments::{
+        AddMissionCommentEntity, EditMissionCommentEntity, MissionCommentEntity,
+    },
+    value_object::{mission_comment_model::MissionCommentModel, pagination::Pagination},
+};
+
+#[async_trait]
+#[automock]
+pub trait MissionCommentRepository {
+    async fn add(&self, add_mission_comment_entity: AddMissionCommentEntity) -> Result<i32>;
+    async fn edit(
+        &self,
+        comment_id: i32,
+        brawler_id: i32,
+        edit_mission_comment_entity: EditMissionCommentEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, comment_id: i32) -> Result<()>;
+    async fn find_by_id(&self, comment_id: i32) -> Result<MissionCommentEntity>;
+    async fn gets(
+        &self,
+        mission_id: i32,
+        pagination: &Pagination,
+    ) -> Result<Vec<MissionCommentModel>>;
+    async fn counting(&self, mission_id: i32) -> Result<i64>;
+}
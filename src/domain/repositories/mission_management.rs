@@ -1,12 +1,30 @@
 use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
 use anyhow::Result;
 use async_trait::async_trait;
+use diesel::PgConnection;
 use mockall::automock;
 
 #[async_trait]
 #[automock]
 pub trait MissionManagementRepository {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
+    fn add_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        add_mission_entity: AddMissionEntity,
+    ) -> Result<i32>;
     async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
+    fn edit_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        edit_mission_entity: EditMissionEntity,
+    ) -> Result<i32>;
+    fn set_tags_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        tags: Vec<String>,
+    ) -> Result<()>;
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
 }
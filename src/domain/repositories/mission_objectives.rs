@@ -0,0 +1,34 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::PgConnection;
+use mockall::automock;
+
+use crate::domain::{
+    entities::mission_objectives::{
+        AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+    },
+    value_object::mission_objective_model::MissionObjectiveModel,
+};
+
+#[async_trait]
+#[automock]
+pub trait MissionObjectiveRepository {
+    async fn add(&self, add_mission_objective_entity: AddMissionObjectiveEntity) -> Result<i32>;
+    fn add_many_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        add_mission_objective_entities: Vec<AddMissionObjectiveEntity>,
+    ) -> Result<()>;
+    async fn edit(
+        &self,
+        objective_id: i32,
+        edit_mission_objective_entity: EditMissionObjectiveEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, objective_id: i32) -> Result<()>;
+    async fn find_by_id(&self, objective_id: i32) -> Result<MissionObjectiveEntity>;
+    async fn gets(&self, mission_id: i32) -> Result<Vec<MissionObjectiveModel>>;
+    async fn next_position(&self, mission_id: i32) -> Result<i32>;
+    async fn complete(&self, objective_id: i32, brawler_id: i32) -> Result<i32>;
+    async fn uncomplete(&self, objective_id: i32) -> Result<i32>;
+    async fn count_incomplete_mandatory(&self, mission_id: i32) -> Result<i64>;
+}
@@ -1,11 +1,22 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use diesel::PgConnection;
 use mockall::automock;
 
+use crate::domain::value_object::mission_statuses::MissionStatuses;
+
 #[async_trait]
 #[automock]
 pub trait MissionOperationRepository {
     async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
     async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
     async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    fn set_status_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        chief_id: i32,
+        expected_from: MissionStatuses,
+        status: MissionStatuses,
+    ) -> Result<i32>;
 }
@@ -0,0 +1,22 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::mission_ratings::{AddMissionRatingEntity, MissionRatingEntity},
+    value_object::mission_rating_model::{MissionRatingModel, ReputationModel},
+};
+
+#[async_trait]
+#[automock]
+pub trait MissionRatingRepository {
+    async fn add(&self, add_mission_rating_entity: AddMissionRatingEntity) -> Result<i32>;
+    async fn find(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        ratee_id: i32,
+    ) -> Result<Option<MissionRatingEntity>>;
+    async fn gets_by_mission(&self, mission_id: i32) -> Result<Vec<MissionRatingModel>>;
+    async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel>;
+}
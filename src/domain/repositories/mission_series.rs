@@ -0,0 +1,41 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::PgConnection;
+use mockall::automock;
+
+use crate::domain::entities::mission_series::{AddMissionSeriesEntity, MissionSeriesEntity};
+use crate::domain::value_object::recurrence::SeriesOccurrenceModel;
+
+// A series is due once its next occurrence has arrived or none of its missions is still
+// Open or InProgress, whichever happens first
+#[async_trait]
+#[automock]
+pub trait MissionSeriesRepository {
+    fn add_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        add_mission_series_entity: AddMissionSeriesEntity,
+    ) -> Result<i32>;
+    async fn find(&self, series_id: i32) -> Result<MissionSeriesEntity>;
+    async fn stop(&self, series_id: i32, chief_id: i32) -> Result<()>;
+    async fn due_ids(&self, limit: i64) -> Result<Vec<i32>>;
+    // Locks the series row, skipping it if another scheduler already holds it or it is no longer due
+    fn lock_due_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        series_id: i32,
+    ) -> Result<Option<MissionSeriesEntity>>;
+    fn latest_mission_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        series_id: i32,
+    ) -> Result<Option<SeriesOccurrenceModel>>;
+    fn advance_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        series_id: i32,
+        next_occurrence_at: NaiveDateTime,
+    ) -> Result<()>;
+    fn deactivate_in_transaction(&self, conn: &mut PgConnection, series_id: i32) -> Result<()>;
+}
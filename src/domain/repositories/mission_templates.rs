@@ -0,0 +1,36 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::PgConnection;
+use mockall::automock;
+
+use crate::domain::{
+    entities::mission_templates::{AddMissionTemplateEntity, MissionTemplateEntity},
+    value_object::mission_template_model::MissionBlueprint,
+};
+
+#[async_trait]
+#[automock]
+pub trait MissionTemplateRepository {
+    // Fails when the owner already keeps `max_per_owner` templates; the count and the insert run
+    // under a lock on the owner, so concurrent saves cannot both squeeze past the cap
+    async fn add(
+        &self,
+        add_mission_template_entity: AddMissionTemplateEntity,
+        max_per_owner: usize,
+    ) -> Result<i32>;
+    async fn find(&self, template_id: i32, owner_id: i32) -> Result<MissionTemplateEntity>;
+    async fn list(&self, owner_id: i32) -> Result<Vec<MissionTemplateEntity>>;
+    async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()>;
+    // Fails unless the mission exists and was led by `chief_id`
+    async fn blueprint_of_mission(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> Result<MissionBlueprint>;
+    fn blueprint_of_mission_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> Result<MissionBlueprint>;
+}
@@ -1,14 +1,32 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
 use crate::domain::value_object::{
-    brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_moddel::MissionModel,
+    brawler_model::BrawlerModel,
+    mission_filter::MissionFilter,
+    mission_moddel::{MissionModel, MissionSearchResultModel},
+    pagination::Pagination,
 };
 
 #[async_trait]
+#[automock]
 pub trait MissionViewingRepository {
     async fn view_detail(&self, mission_id: i32) -> Result<MissionModel>;
-    async fn gets(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>>;
+    async fn gets(
+        &self,
+        filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        max_crew_per_mission: u32,
+        pagination: &Pagination,
+    ) -> Result<Vec<MissionSearchResultModel>>;
+    async fn counting(
+        &self,
+        filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        max_crew_per_mission: u32,
+    ) -> Result<i64>;
     async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
+    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool>;
     async fn get_mission_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
 }
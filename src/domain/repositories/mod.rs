@@ -1,8 +1,22 @@
 pub mod brawlers;
+pub mod calendar;
+pub mod conversations;
 pub mod crew_oparation;
 pub mod friendships;
+pub mod maintenance;
 pub mod messages;
+pub mod mission_comments;
 pub mod mission_management;
+pub mod mission_objectives;
 pub mod mission_operation;
+pub mod mission_ratings;
+pub mod mission_series;
+pub mod mission_templates;
 pub mod mission_viewing;
+pub mod notifications;
+pub mod outbox;
+pub mod progression;
+pub mod rate_limits;
+pub mod tags;
 pub mod transaction_provider;
+pub mod webhooks;
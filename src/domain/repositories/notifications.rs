@@ -0,0 +1,26 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::notifications::{AddNotificationEntity, NotificationEntity},
+    value_object::pagination::Pagination,
+};
+
+#[async_trait]
+#[automock]
+pub trait NotificationRepository {
+    async fn add(
+        &self,
+        add_notification_entities: Vec<AddNotificationEntity>,
+    ) -> Result<Vec<NotificationEntity>>;
+    async fn gets(
+        &self,
+        recipient_id: i32,
+        pagination: &Pagination,
+    ) -> Result<Vec<NotificationEntity>>;
+    async fn counting(&self, recipient_id: i32) -> Result<i64>;
+    async fn unread_counting(&self, recipient_id: i32) -> Result<i64>;
+    async fn mark_read(&self, notification_id: i32, recipient_id: i32) -> Result<usize>;
+    async fn mark_all_read(&self, recipient_id: i32) -> Result<usize>;
+}
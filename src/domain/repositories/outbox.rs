@@ -0,0 +1,31 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::PgConnection;
+use mockall::automock;
+
+use crate::domain::{
+    entities::outbox::OutboxEntity, events::DomainEvent, value_object::outbox_model::OutboxLagModel,
+};
+
+#[async_trait]
+#[automock]
+pub trait OutboxRepository {
+    // Runs on the caller's connection so the events commit or roll back with the state change
+    fn stage(&self, conn: &mut PgConnection, events: Vec<DomainEvent>) -> Result<()>;
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_seconds: i64,
+        max_attempts: i32,
+    ) -> Result<Vec<OutboxEntity>>;
+    async fn mark_handled(&self, outbox_id: i64, subscriber: String) -> Result<()>;
+    async fn mark_processed(&self, outbox_id: i64) -> Result<()>;
+    async fn mark_retry(
+        &self,
+        outbox_id: i64,
+        error: String,
+        next_attempt_at: NaiveDateTime,
+    ) -> Result<()>;
+    async fn get_lag(&self, max_attempts: i32) -> Result<OutboxLagModel>;
+}
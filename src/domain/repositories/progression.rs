@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::progression::{AddBrawlerAchievementEntity, AddXpAwardEntity},
+    value_object::progression_model::{ProgressionStatsModel, UnlockedAchievementModel},
+};
+
+#[async_trait]
+#[automock]
+pub trait ProgressionRepository {
+    async fn award_xp(&self, add_xp_award_entities: Vec<AddXpAwardEntity>) -> Result<usize>;
+    async fn unlock(
+        &self,
+        add_brawler_achievement_entities: Vec<AddBrawlerAchievementEntity>,
+    ) -> Result<usize>;
+    async fn get_stats(&self, brawler_id: i32) -> Result<ProgressionStatsModel>;
+    async fn get_unlocked(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievementModel>>;
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::value_object::rate_limit_model::{RateLimitDecision, RateLimitRule};
+
+#[async_trait]
+#[automock]
+pub trait RateLimitRepository {
+    // Takes one token from the bucket behind `key`, creating a full bucket on first use
+    async fn take(&self, key: String, rule: RateLimitRule) -> Result<RateLimitDecision>;
+}
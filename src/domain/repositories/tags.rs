@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::value_object::tag_model::TagModel;
+
+#[async_trait]
+#[automock]
+pub trait TagRepository {
+    // Only tags on live missions are counted, most used first
+    async fn suggest(&self, prefix: Option<String>, limit: i64) -> Result<Vec<TagModel>>;
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{
+    entities::webhooks::{AddWebhookEntity, WebhookDeliveryEntity, WebhookEntity},
+    value_object::{pagination::Pagination, webhook_model::WebhookDeliveryJobModel},
+};
+
+#[async_trait]
+#[automock]
+pub trait WebhookRepository {
+    async fn add(&self, add_webhook_entity: AddWebhookEntity) -> Result<WebhookEntity>;
+    async fn remove(&self, webhook_id: i32, owner_id: i32) -> Result<usize>;
+    async fn find_by_id(&self, webhook_id: i32) -> Result<WebhookEntity>;
+    async fn gets_by_owner(&self, owner_id: i32) -> Result<Vec<WebhookEntity>>;
+    async fn enqueue(
+        &self,
+        mission_id: i32,
+        event: String,
+        payload: serde_json::Value,
+    ) -> Result<usize>;
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_seconds: i64,
+    ) -> Result<Vec<WebhookDeliveryJobModel>>;
+    async fn mark_delivered(&self, delivery_id: i32, status_code: i32) -> Result<()>;
+    async fn mark_retry(
+        &self,
+        delivery_id: i32,
+        status_code: Option<i32>,
+        error: String,
+        next_attempt_at: NaiveDateTime,
+    ) -> Result<()>;
+    async fn mark_failed(
+        &self,
+        delivery_id: i32,
+        status_code: Option<i32>,
+        error: String,
+    ) -> Result<()>;
+    async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        pagination: &Pagination,
+    ) -> Result<Vec<WebhookDeliveryEntity>>;
+    async fn counting_deliveries(&self, webhook_id: i32) -> Result<i64>;
+}
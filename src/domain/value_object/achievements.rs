@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_object::progression_model::ProgressionStatsModel;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Achievements {
+    FirstMission,
+    TenCompletions,
+    FirstCommand,
+    SeasonedChief,
+    TeamPlayer,
+    Veteran,
+}
+
+impl Display for Achievements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Achievements::FirstMission => write!(f, "FirstMission"),
+            Achievements::TenCompletions => write!(f, "TenCompletions"),
+            Achievements::FirstCommand => write!(f, "FirstCommand"),
+            Achievements::SeasonedChief => write!(f, "SeasonedChief"),
+            Achievements::TeamPlayer => write!(f, "TeamPlayer"),
+            Achievements::Veteran => write!(f, "Veteran"),
+        }
+    }
+}
+
+impl Achievements {
+    pub const ALL: [Achievements; 6] = [
+        Achievements::FirstMission,
+        Achievements::TenCompletions,
+        Achievements::FirstCommand,
+        Achievements::SeasonedChief,
+        Achievements::TeamPlayer,
+        Achievements::Veteran,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Achievements::FirstMission => "First Mission",
+            Achievements::TenCompletions => "Ten Completions",
+            Achievements::FirstCommand => "First Command",
+            Achievements::SeasonedChief => "Seasoned Chief",
+            Achievements::TeamPlayer => "Team Player",
+            Achievements::Veteran => "Veteran",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievements::FirstMission => "Complete a mission as crew",
+            Achievements::TenCompletions => "Complete 10 missions as crew",
+            Achievements::FirstCommand => "Lead a mission to completion",
+            Achievements::SeasonedChief => "Lead 5 missions to completion",
+            Achievements::TeamPlayer => "Join 5 missions",
+            Achievements::Veteran => "Earn 1000 XP",
+        }
+    }
+
+    pub fn is_earned(&self, stats: &ProgressionStatsModel) -> bool {
+        match self {
+            Achievements::FirstMission => stats.completed_count >= 1,
+            Achievements::TenCompletions => stats.completed_count >= 10,
+            Achievements::FirstCommand => stats.led_count >= 1,
+            Achievements::SeasonedChief => stats.led_count >= 5,
+            Achievements::TeamPlayer => stats.joined_count >= 5,
+            Achievements::Veteran => stats.xp >= 1000,
+        }
+    }
+
+    pub fn earned(stats: &ProgressionStatsModel) -> Vec<Achievements> {
+        Self::ALL
+            .into_iter()
+            .filter(|achievement| achievement.is_earned(stats))
+            .collect()
+    }
+}
@@ -1,6 +1,6 @@
 use diesel::{
     prelude::QueryableByName,
-    sql_types::{BigInt, Varchar},
+    sql_types::{Array, BigInt, Double, Int4, Nullable, Text, Varchar},
 };
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,8 @@ impl RegisterBrawlerModel {
 
 #[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
 pub struct BrawlerModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
     #[diesel(sql_type = Varchar)]
     pub display_name: String,
     #[diesel(sql_type = Varchar)]
@@ -40,6 +42,48 @@ pub struct BrawlerModel {
     pub mission_success_count: i64,
     #[diesel(sql_type = BigInt)]
     pub mission_joined_count: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub rating_average: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub abandoned_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub xp: i64,
+    #[diesel(sql_type = Int4)]
+    pub level: i32,
+    #[diesel(sql_type = Array<Text>)]
+    pub achievements: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct BrawlerProfileModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub tag: String,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    pub mission_success_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub mission_joined_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub mission_led_count: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub rating_average: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub abandoned_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub xp: i64,
+    #[diesel(sql_type = Int4)]
+    pub level: i32,
+    #[diesel(sql_type = Array<Text>)]
+    pub achievements: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+// The token is only shown once; rotating it invalidates every previously shared feed URL
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarTokenModel {
+    pub token: String,
+    pub feed_path: String,
+}
@@ -0,0 +1,19 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CommentVisibility {
+    #[default]
+    Public,
+    CrewOnly,
+}
+
+impl Display for CommentVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentVisibility::Public => write!(f, "Public"),
+            CommentVisibility::CrewOnly => write!(f, "CrewOnly"),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Int4, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct ConversationSummaryModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub mission_id: Option<i32>,
+    #[diesel(sql_type = Varchar)]
+    pub name: String,
+    #[diesel(sql_type = BigInt)]
+    pub member_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub unread_count: i64,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub last_message_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum MissionCategory {
+    Combat,
+    Exploration,
+    Social,
+    Training,
+    Event,
+    Other,
+}
+
+impl Display for MissionCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionCategory::Combat => write!(f, "Combat"),
+            MissionCategory::Exploration => write!(f, "Exploration"),
+            MissionCategory::Social => write!(f, "Social"),
+            MissionCategory::Training => write!(f, "Training"),
+            MissionCategory::Event => write!(f, "Event"),
+            MissionCategory::Other => write!(f, "Other"),
+        }
+    }
+}
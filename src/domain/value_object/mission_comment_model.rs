@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{Int4, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_comments::{
+    AddMissionCommentEntity, EditMissionCommentEntity,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionCommentModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type = Text)]
+    pub content: String,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type = Timestamp)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionCommentModel {
+    pub content: String,
+}
+
+impl AddMissionCommentModel {
+    pub fn to_entity(&self, mission_id: i32, brawler_id: i32) -> AddMissionCommentEntity {
+        AddMissionCommentEntity {
+            mission_id,
+            brawler_id,
+            content: self.content.trim().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionCommentModel {
+    pub content: String,
+}
+
+impl EditMissionCommentModel {
+    pub fn to_entity(&self) -> EditMissionCommentEntity {
+        EditMissionCommentEntity {
+            content: self.content.trim().to_string(),
+        }
+    }
+}
@@ -1,10 +1,125 @@
+use anyhow::Result;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_object::mission_statuses::MissionStatuses;
+use crate::domain::{
+    constants::MAX_SEARCH_TERMS,
+    value_object::{
+        mission_category::MissionCategory,
+        mission_sort::MissionSort,
+        mission_statuses::MissionStatuses,
+        tag_model::{TagMatch, normalize_tag},
+    },
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MissionFilter {
     pub name: Option<String>,
+    pub q: Option<String>,
     pub status: Option<MissionStatuses>,
+    // Comma separated, e.g. "Open,Failed"
+    pub statuses: Option<String>,
     pub brawler_id: Option<i32>,
+    pub chief_id: Option<i32>,
+    pub has_free_slots: Option<bool>,
+    pub not_joined: Option<bool>,
+    pub created_from: Option<NaiveDate>,
+    pub created_to: Option<NaiveDate>,
+    pub sort: Option<MissionSort>,
+    pub category: Option<MissionCategory>,
+    // Comma separated, e.g. "raid,ranked"
+    pub tags: Option<String>,
+    pub tag_match: Option<TagMatch>,
+    pub series_id: Option<i32>,
+}
+
+impl MissionFilter {
+    // Turns free text into a tsquery where every word must match and the last typed letters
+    // may still be incomplete ("drag ra" finds "Dragon raid"). Punctuation is dropped so user
+    // input can never inject tsquery operators
+    pub fn search_query(&self) -> Option<String> {
+        let terms = self
+            .q
+            .as_deref()?
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .take(MAX_SEARCH_TERMS)
+            .map(|term| format!("{}:*", term.to_lowercase()))
+            .collect::<Vec<_>>();
+
+        if terms.is_empty() {
+            None
+        } else {
+            Some(terms.join(" & "))
+        }
+    }
+
+    pub fn status_list(&self) -> Result<Vec<MissionStatuses>> {
+        let mut statuses = self
+            .statuses
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|status| !status.is_empty())
+            .map(str::parse::<MissionStatuses>)
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(status) = &self.status
+            && !statuses.contains(status)
+        {
+            statuses.push(status.clone());
+        }
+
+        Ok(statuses)
+    }
+
+    pub fn tag_list(&self) -> Result<Vec<String>> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self
+            .tags
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter(|tag| !tag.trim().is_empty())
+        {
+            let tag = normalize_tag(tag)?;
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        Ok(tags)
+    }
+
+    pub fn effective_sort(&self) -> MissionSort {
+        match &self.sort {
+            Some(sort) => sort.clone(),
+            None if self.search_query().is_some() => MissionSort::Relevance,
+            None => MissionSort::Newest,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.status_list()?;
+        self.tag_list()?;
+
+        if let (Some(from), Some(to)) = (self.created_from, self.created_to)
+            && from > to
+        {
+            return Err(anyhow::anyhow!(
+                "created_from ({}) must not be after created_to ({})",
+                from,
+                to
+            ));
+        }
+
+        if self.sort == Some(MissionSort::Relevance) && self.search_query().is_none() {
+            return Err(anyhow::anyhow!(
+                "Sorting by Relevance requires a search query"
+            ));
+        }
+
+        Ok(())
+    }
 }
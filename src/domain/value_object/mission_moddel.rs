@@ -1,13 +1,16 @@
 use chrono::NaiveDateTime;
 use diesel::{
     QueryableByName,
-    sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar},
+    sql_types::{Array, BigInt, Bool, Double, Int4, Nullable, Text, Timestamp, Varchar},
 };
 use serde::{Deserialize, Serialize};
 
 use crate::domain::{
     entities::missions::{AddMissionEntity, EditMissionEntity},
-    value_object::mission_statuses::MissionStatuses,
+    value_object::{
+        comment_visibility::CommentVisibility, mission_category::MissionCategory,
+        mission_statuses::MissionStatuses, recurrence::RecurrenceModel,
+    },
 };
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
 pub struct MissionModel {
@@ -25,16 +28,61 @@ pub struct MissionModel {
     pub chief_display_name: String,
     #[diesel(sql_type = BigInt)]
     pub crew_count: i64,
+    #[diesel(sql_type = Varchar)]
+    pub comment_visibility: String,
+    #[diesel(sql_type = Bool)]
+    pub require_objectives: bool,
+    #[diesel(sql_type = Double)]
+    pub completion_percentage: f64,
     #[diesel(sql_type = Timestamp)]
     pub created_at: NaiveDateTime,
     #[diesel(sql_type = Timestamp)]
     pub updated_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub finished_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub deadline: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub category: Option<String>,
+    #[diesel(sql_type = Array<Text>)]
+    pub tags: Vec<String>,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub series_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub scheduled_for: Option<NaiveDateTime>,
+}
+
+// Highlights are HTML-escaped user text with the matched words wrapped in <mark> tags, so they can
+// be inserted as HTML as they are
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionSearchResultModel {
+    #[diesel(embed)]
+    #[serde(flatten)]
+    pub mission: MissionModel,
+    #[diesel(sql_type = Double)]
+    pub search_rank: f64,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub name_highlight: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub description_snippet: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
+    #[serde(default)]
+    pub comment_visibility: Option<CommentVisibility>,
+    #[serde(default)]
+    pub require_objectives: bool,
+    #[serde(default)]
+    pub deadline: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub category: Option<MissionCategory>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub recurrence: Option<RecurrenceModel>,
 }
 
 impl AddMissionModel {
@@ -43,6 +91,16 @@ impl AddMissionModel {
             name: self.name.clone(),
             description: self.description.clone(),
             status: MissionStatuses::Open.to_string(),
+            comment_visibility: self
+                .comment_visibility
+                .clone()
+                .unwrap_or_default()
+                .to_string(),
+            require_objectives: self.require_objectives,
+            deadline: self.deadline,
+            category: self.category.as_ref().map(|c| c.to_string()),
+            series_id: None,
+            scheduled_for: None,
             chief_id,
         }
     }
@@ -53,6 +111,17 @@ pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
     pub status: Option<String>,
+    #[serde(default)]
+    pub comment_visibility: Option<CommentVisibility>,
+    #[serde(default)]
+    pub require_objectives: Option<bool>,
+    #[serde(default)]
+    pub deadline: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub category: Option<MissionCategory>,
+    // Replaces the whole tag set when present; an empty list clears it
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
 }
 
 impl EditMissionModel {
@@ -62,6 +131,10 @@ impl EditMissionModel {
             name: self.name.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
+            comment_visibility: self.comment_visibility.as_ref().map(|v| v.to_string()),
+            require_objectives: self.require_objectives,
+            deadline: self.deadline,
+            category: self.category.as_ref().map(|c| c.to_string()),
             chief_id,
         }
     }
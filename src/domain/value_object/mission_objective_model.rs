@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{Bool, Int4, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_objectives::EditMissionObjectiveEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionObjectiveModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub position: i32,
+    #[diesel(sql_type = Varchar)]
+    pub title: String,
+    #[diesel(sql_type = Bool)]
+    pub is_mandatory: bool,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub completed_by: Option<i32>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub completed_by_display_name: Option<String>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionObjectiveModel {
+    pub title: String,
+    #[serde(default = "default_mandatory")]
+    pub is_mandatory: bool,
+}
+
+fn default_mandatory() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionObjectiveModel {
+    pub position: Option<i32>,
+    pub title: Option<String>,
+    pub is_mandatory: Option<bool>,
+}
+
+impl EditMissionObjectiveModel {
+    pub fn to_entity(&self) -> EditMissionObjectiveEntity {
+        EditMissionObjectiveEntity {
+            position: self.position,
+            title: self.title.clone(),
+            is_mandatory: self.is_mandatory,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use chrono::{NaiveDateTime, TimeDelta};
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Double, Int2, Int4, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    constants::ABANDONMENT_WINDOW_HOURS, entities::mission_ratings::AddMissionRatingEntity,
+    value_object::mission_statuses::MissionStatuses,
+};
+
+// Crew may only leave while a mission is Open or Failed. Leaving an Open mission early is fine and
+// leaving one that already failed costs nothing, so abandoning means leaving an Open mission
+// once its deadline is less than ABANDONMENT_WINDOW_HOURS away, or already past
+pub fn is_abandonment(
+    mission_status: &str,
+    deadline: Option<NaiveDateTime>,
+    left_at: NaiveDateTime,
+) -> bool {
+    let Some(deadline) = deadline else {
+        return false;
+    };
+
+    mission_status == MissionStatuses::Open.to_string()
+        && left_at >= deadline - TimeDelta::hours(ABANDONMENT_WINDOW_HOURS)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionRatingModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub rater_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub rater_display_name: String,
+    #[diesel(sql_type = Int4)]
+    pub ratee_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub ratee_display_name: String,
+    #[diesel(sql_type = Int2)]
+    pub score: i16,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub comment: Option<String>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionRatingModel {
+    pub ratee_id: i32,
+    pub score: i16,
+    pub comment: Option<String>,
+}
+
+impl AddMissionRatingModel {
+    pub fn to_entity(&self, mission_id: i32, rater_id: i32) -> AddMissionRatingEntity {
+        AddMissionRatingEntity {
+            mission_id,
+            rater_id,
+            ratee_id: self.ratee_id,
+            score: self.score,
+            comment: self
+                .comment
+                .as_ref()
+                .map(|comment| comment.trim().to_string())
+                .filter(|comment| !comment.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct ReputationModel {
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub rating_average: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub abandoned_count: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn abandonment_is_leaving_an_open_mission_close_to_its_deadline() {
+        let deadline = Some(at(10, 12));
+        let open = MissionStatuses::Open.to_string();
+        let failed = MissionStatuses::Failed.to_string();
+
+        let cases = [
+            (open.as_str(), deadline, at(8, 12), false),
+            (open.as_str(), deadline, at(9, 11), false),
+            (open.as_str(), deadline, at(9, 12), true),
+            (open.as_str(), deadline, at(10, 11), true),
+            (open.as_str(), deadline, at(11, 12), true),
+            (open.as_str(), None, at(10, 11), false),
+            (failed.as_str(), deadline, at(10, 11), false),
+            (failed.as_str(), deadline, at(11, 12), false),
+        ];
+
+        for (status, deadline, left_at, expected) in cases {
+            assert_eq!(
+                is_abandonment(status, deadline, left_at),
+                expected,
+                "{} mission, deadline {:?}, left at {}",
+                status,
+                deadline,
+                left_at
+            );
+        }
+    }
+}
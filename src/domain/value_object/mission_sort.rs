@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum MissionSort {
+    Relevance,
+    Newest,
+    MostCrew,
+    FewestFreeSlots,
+    Deadline,
+}
+
+impl Display for MissionSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionSort::Relevance => write!(f, "Relevance"),
+            MissionSort::Newest => write!(f, "Newest"),
+            MissionSort::MostCrew => write!(f, "MostCrew"),
+            MissionSort::FewestFreeSlots => write!(f, "FewestFreeSlots"),
+            MissionSort::Deadline => write!(f, "Deadline"),
+        }
+    }
+}
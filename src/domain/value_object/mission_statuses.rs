@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
@@ -21,3 +21,17 @@ impl Display for MissionStatuses {
         }
     }
 }
+
+impl FromStr for MissionStatuses {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Open" => Ok(MissionStatuses::Open),
+            "InProgress" => Ok(MissionStatuses::InProgress),
+            "Completed" => Ok(MissionStatuses::Completed),
+            "Failed" => Ok(MissionStatuses::Failed),
+            _ => Err(anyhow::anyhow!("Unknown mission status: {}", s)),
+        }
+    }
+}
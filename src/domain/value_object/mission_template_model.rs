@@ -0,0 +1,102 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::{
+        mission_objectives::AddMissionObjectiveEntity, mission_templates::AddMissionTemplateEntity,
+        missions::AddMissionEntity,
+    },
+    value_object::mission_statuses::MissionStatuses,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TemplateObjectiveModel {
+    pub title: String,
+    pub is_mandatory: bool,
+}
+
+// Everything a new mission copies from a template or from the mission it clones. Crew,
+// status, deadline and objective progress are deliberately left behind
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionBlueprint {
+    pub name: String,
+    pub description: Option<String>,
+    pub comment_visibility: String,
+    pub require_objectives: bool,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub objectives: Vec<TemplateObjectiveModel>,
+}
+
+impl MissionBlueprint {
+    pub fn to_template_entity(&self, owner_id: i32) -> Result<AddMissionTemplateEntity> {
+        Ok(AddMissionTemplateEntity {
+            owner_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            comment_visibility: self.comment_visibility.clone(),
+            require_objectives: self.require_objectives,
+            category: self.category.clone(),
+            tags: self.tags.clone(),
+            objectives: serde_json::to_value(&self.objectives)?,
+        })
+    }
+
+    pub fn to_mission_entity(
+        &self,
+        chief_id: i32,
+        deadline: Option<NaiveDateTime>,
+    ) -> AddMissionEntity {
+        AddMissionEntity {
+            chief_id,
+            name: self.name.clone(),
+            status: MissionStatuses::Open.to_string(),
+            description: self.description.clone(),
+            comment_visibility: self.comment_visibility.clone(),
+            require_objectives: self.require_objectives,
+            deadline,
+            category: self.category.clone(),
+            series_id: None,
+            scheduled_for: None,
+        }
+    }
+
+    pub fn to_objective_entities(&self, mission_id: i32) -> Vec<AddMissionObjectiveEntity> {
+        self.objectives
+            .iter()
+            .zip(1..)
+            .map(|(objective, position)| AddMissionObjectiveEntity {
+                mission_id,
+                position,
+                title: objective.title.clone(),
+                is_mandatory: objective.is_mandatory,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionTemplateModel {
+    pub id: i32,
+    #[serde(flatten)]
+    pub blueprint: MissionBlueprint,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SaveMissionTemplateModel {
+    // Defaults to the mission's own name
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct InstantiateMissionModel {
+    // Defaults to the template's or source mission's name
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub deadline: Option<NaiveDateTime>,
+}
@@ -1,6 +1,29 @@
+pub mod achievements;
 pub mod base64_image;
 pub mod brawler_model;
+pub mod calendar_model;
+pub mod comment_visibility;
+pub mod conversation_model;
+pub mod health_model;
+pub mod maintenance_model;
+pub mod mission_category;
+pub mod mission_comment_model;
 pub mod mission_filter;
 pub mod mission_moddel;
+pub mod mission_objective_model;
+pub mod mission_rating_model;
+pub mod mission_sort;
 pub mod mission_statuses;
+pub mod mission_template_model;
+pub mod notification_kinds;
+pub mod notification_model;
+pub mod outbox_model;
+pub mod pagination;
+pub mod progression_model;
+pub mod rate_limit_model;
+pub mod recurrence;
+pub mod tag_model;
 pub mod upload_image;
+pub mod webhook_delivery_statuses;
+pub mod webhook_events;
+pub mod webhook_model;
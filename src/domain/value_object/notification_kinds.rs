@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum NotificationKinds {
+    CrewJoined,
+    CrewLeft,
+    MissionStatusChanged,
+    FriendAdded,
+    MessageReceived,
+}
+
+impl Display for NotificationKinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationKinds::CrewJoined => write!(f, "CrewJoined"),
+            NotificationKinds::CrewLeft => write!(f, "CrewLeft"),
+            NotificationKinds::MissionStatusChanged => write!(f, "MissionStatusChanged"),
+            NotificationKinds::FriendAdded => write!(f, "FriendAdded"),
+            NotificationKinds::MessageReceived => write!(f, "MessageReceived"),
+        }
+    }
+}
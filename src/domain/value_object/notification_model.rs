@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::notifications::NotificationEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationModel {
+    pub id: i32,
+    pub recipient_id: i32,
+    pub actor_id: Option<i32>,
+    pub mission_id: Option<i32>,
+    pub kind: String,
+    pub content: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<NotificationEntity> for NotificationModel {
+    fn from(entity: NotificationEntity) -> Self {
+        Self {
+            id: entity.id,
+            recipient_id: entity.recipient_id,
+            actor_id: entity.actor_id,
+            mission_id: entity.mission_id,
+            kind: entity.kind,
+            content: entity.content,
+            read_at: entity.read_at,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnreadCountModel {
+    pub unread_count: i64,
+}
@@ -0,0 +1,25 @@
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Double},
+};
+use serde::{Deserialize, Serialize};
+
+// Lag is measured from the commit that recorded an event to the moment every subscriber has
+// handled it, so a slow or stuck relay shows up here before users notice missing side effects
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct OutboxLagModel {
+    #[diesel(sql_type = BigInt)]
+    pub pending_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub retrying_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub dead_count: i64,
+    #[diesel(sql_type = Double)]
+    pub oldest_pending_seconds: f64,
+    #[diesel(sql_type = BigInt)]
+    pub processed_last_hour: i64,
+    #[diesel(sql_type = Double)]
+    pub avg_lag_ms_last_hour: f64,
+    #[diesel(sql_type = Double)]
+    pub max_lag_ms_last_hour: f64,
+}
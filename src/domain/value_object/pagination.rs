@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE, MAX_PAGE_SIZE};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Pagination {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+impl Pagination {
+    // Capped so the offset stays far from overflowing whatever the query string says
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).clamp(1, MAX_PAGE)
+    }
+
+    pub fn page_size(&self) -> i64 {
+        self.page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> i64 {
+        (self.page() - 1) * self.page_size()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedModel<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+impl<T> PaginatedModel<T> {
+    pub fn new(items: Vec<T>, pagination: &Pagination, total: i64) -> Self {
+        Self {
+            items,
+            page: pagination.page(),
+            page_size: pagination.page_size(),
+            total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn huge_pages_are_capped_instead_of_overflowing_the_offset() {
+        let pagination = Pagination {
+            page: Some(i64::MAX),
+            page_size: Some(i64::MAX),
+        };
+
+        assert_eq!(pagination.page(), MAX_PAGE);
+        assert_eq!(pagination.offset(), (MAX_PAGE - 1) * MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn pages_below_one_start_at_the_first_page() {
+        let pagination = Pagination {
+            page: Some(i64::MIN),
+            page_size: None,
+        };
+
+        assert_eq!(pagination.offset(), 0);
+    }
+}
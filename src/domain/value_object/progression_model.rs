@@ -0,0 +1,57 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Int4, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_object::achievements::Achievements;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct ProgressionStatsModel {
+    #[diesel(sql_type = BigInt)]
+    pub xp: i64,
+    #[diesel(sql_type = Int4)]
+    pub level: i32,
+    #[diesel(sql_type = BigInt)]
+    pub completed_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub led_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub joined_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct UnlockedAchievementModel {
+    #[diesel(sql_type = Varchar)]
+    pub achievement: String,
+    #[diesel(sql_type = Timestamp)]
+    pub unlocked_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AchievementModel {
+    pub code: String,
+    pub title: String,
+    pub description: String,
+    pub unlocked_at: Option<NaiveDateTime>,
+}
+
+impl AchievementModel {
+    pub fn new(achievement: Achievements, unlocked_at: Option<NaiveDateTime>) -> Self {
+        Self {
+            code: achievement.to_string(),
+            title: achievement.title().to_string(),
+            description: achievement.description().to_string(),
+            unlocked_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgressionModel {
+    pub brawler_id: i32,
+    pub xp: i64,
+    pub level: i32,
+    pub achievements: Vec<AchievementModel>,
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+// Token bucket: holds up to `requests` tokens and refills all of them over `period_seconds`.
+// Written as `requests/period_seconds` in config, e.g. `10/60`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct RateLimitRule {
+    pub requests: u32,
+    pub period_seconds: u32,
+}
+
+impl RateLimitRule {
+    pub fn refill_per_second(&self) -> f64 {
+        f64::from(self.requests) / f64::from(self.period_seconds)
+    }
+
+    // Seconds until a bucket holding `tokens` has a whole token again
+    pub fn retry_after_seconds(&self, tokens: f64) -> u64 {
+        ((1.0 - tokens).max(0.0) / self.refill_per_second())
+            .ceil()
+            .max(1.0) as u64
+    }
+}
+
+impl FromStr for RateLimitRule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (requests, period_seconds) = s
+            .split_once('/')
+            .ok_or_else(|| anyhow::anyhow!("expected requests/seconds, e.g. 10/60"))?;
+        let rule = Self {
+            requests: requests.trim().parse()?,
+            period_seconds: period_seconds.trim().parse()?,
+        };
+
+        if rule.requests == 0 || rule.period_seconds == 0 {
+            return Err(anyhow::anyhow!(
+                "requests and seconds must be greater than 0"
+            ));
+        }
+
+        Ok(rule)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub remaining: u32,
+    pub retry_after_seconds: u64,
+}
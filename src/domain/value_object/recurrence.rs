@@ -0,0 +1,155 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::Result;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, TimeDelta, Weekday};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Display for RecurrenceFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceFrequency::Daily => write!(f, "Daily"),
+            RecurrenceFrequency::Weekly => write!(f, "Weekly"),
+            RecurrenceFrequency::Monthly => write!(f, "Monthly"),
+        }
+    }
+}
+
+impl FromStr for RecurrenceFrequency {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Daily" => Ok(RecurrenceFrequency::Daily),
+            "Weekly" => Ok(RecurrenceFrequency::Weekly),
+            "Monthly" => Ok(RecurrenceFrequency::Monthly),
+            _ => Err(anyhow::anyhow!("Unknown recurrence frequency: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecurrenceModel {
+    pub frequency: RecurrenceFrequency,
+    // Only for Weekly, e.g. ["Mon", "Thu"]
+    #[serde(default)]
+    pub weekdays: Vec<Weekday>,
+    // Defaults to now. Its time of day (and day of month for Monthly) is kept for every occurrence
+    #[serde(default)]
+    pub starts_at: Option<NaiveDateTime>,
+}
+
+impl RecurrenceModel {
+    pub fn to_rule(&self, now: NaiveDateTime) -> Result<RecurrenceRule> {
+        let rule = RecurrenceRule {
+            frequency: self.frequency.clone(),
+            weekdays: self.weekdays.clone(),
+            starts_at: self.starts_at.unwrap_or(now),
+        };
+        rule.validate()?;
+
+        Ok(rule)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    pub weekdays: Vec<Weekday>,
+    pub starts_at: NaiveDateTime,
+}
+
+impl RecurrenceRule {
+    pub fn validate(&self) -> Result<()> {
+        match self.frequency {
+            RecurrenceFrequency::Weekly if self.weekdays.is_empty() => Err(anyhow::anyhow!(
+                "Weekly recurrence needs at least one weekday"
+            )),
+            RecurrenceFrequency::Daily | RecurrenceFrequency::Monthly
+                if !self.weekdays.is_empty() =>
+            {
+                Err(anyhow::anyhow!(
+                    "Weekdays can only be set on a Weekly recurrence"
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // A start in the past only anchors the time of day; the first occurrence is never before now
+    pub fn first_occurrence(&self, now: NaiveDateTime) -> NaiveDateTime {
+        self.next_after(now.max(self.starts_at) - TimeDelta::nanoseconds(1))
+    }
+
+    // The earliest occurrence strictly after `after`, never before `starts_at`
+    pub fn next_after(&self, after: NaiveDateTime) -> NaiveDateTime {
+        let after = after.max(self.starts_at - TimeDelta::nanoseconds(1));
+        let time = self.starts_at.time();
+        let mut date = after.date();
+
+        match self.frequency {
+            RecurrenceFrequency::Daily => {
+                if date.and_time(time) <= after {
+                    date = date + Days::new(1);
+                }
+                date.and_time(time)
+            }
+            RecurrenceFrequency::Weekly => {
+                for _ in 0..8 {
+                    if self.weekdays.contains(&date.weekday()) && date.and_time(time) > after {
+                        return date.and_time(time);
+                    }
+                    date = date + Days::new(1);
+                }
+                after.date().and_time(time) + Days::new(7)
+            }
+            RecurrenceFrequency::Monthly => {
+                let (mut year, mut month) = (date.year(), date.month());
+                loop {
+                    let candidate = clamped_date(year, month, self.starts_at.day()).and_time(time);
+                    if candidate > after {
+                        return candidate;
+                    }
+                    (year, month) = if month == 12 {
+                        (year + 1, 1)
+                    } else {
+                        (year, month + 1)
+                    };
+                }
+            }
+        }
+    }
+}
+
+// Day 31 falls back to the last day of shorter months
+fn clamped_date(year: i32, month: u32, day: u32) -> NaiveDate {
+    (1..=day)
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .unwrap_or_default()
+}
+
+// The occurrence the next one is copied from. Its deadline is kept at the same distance from the
+// start, so a mission due two hours after it begins stays that way every week
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesOccurrenceModel {
+    pub mission_id: i32,
+    pub deadline_offset: Option<TimeDelta>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionSeriesModel {
+    pub id: i32,
+    pub chief_id: i32,
+    #[serde(flatten)]
+    pub rule: RecurrenceRule,
+    pub next_occurrence_at: NaiveDateTime,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+}
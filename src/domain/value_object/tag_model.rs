@@ -0,0 +1,76 @@
+use anyhow::Result;
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::constants::{MAX_TAG_LENGTH, MAX_TAGS_PER_MISSION};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct TagModel {
+    #[diesel(sql_type = Varchar)]
+    pub name: String,
+    #[diesel(sql_type = BigInt)]
+    pub usage_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TagSuggestionFilter {
+    pub prefix: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
+// Tags are stored lowercase with inner whitespace turned into dashes, so "Ranked  Raid" and
+// "ranked-raid" are the same tag. Only letters, digits and dashes are kept
+pub fn normalize_tag(tag: &str) -> Result<String> {
+    let normalized = tag
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+
+    if normalized.is_empty() {
+        return Err(anyhow::anyhow!("Tag must not be empty"));
+    }
+    if normalized.chars().count() > MAX_TAG_LENGTH {
+        return Err(anyhow::anyhow!(
+            "Tag must be at most {} characters long",
+            MAX_TAG_LENGTH
+        ));
+    }
+    if !normalized.chars().all(|c| c.is_alphanumeric() || c == '-') {
+        return Err(anyhow::anyhow!(
+            "Tag '{}' may only contain letters, digits and dashes",
+            tag
+        ));
+    }
+
+    Ok(normalized)
+}
+
+pub fn normalize_tags(tags: &[String]) -> Result<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = normalize_tag(tag)?;
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+
+    if normalized.len() > MAX_TAGS_PER_MISSION {
+        return Err(anyhow::anyhow!(
+            "A mission can have at most {} tags",
+            MAX_TAGS_PER_MISSION
+        ));
+    }
+
+    Ok(normalized)
+}
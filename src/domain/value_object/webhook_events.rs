@@ -0,0 +1,23 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum WebhookEvents {
+    #[serde(rename = "mission.created")]
+    MissionCreated,
+    #[serde(rename = "mission.status_changed")]
+    MissionStatusChanged,
+    #[serde(rename = "crew.joined")]
+    CrewJoined,
+}
+
+impl Display for WebhookEvents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEvents::MissionCreated => write!(f, "mission.created"),
+            WebhookEvents::MissionStatusChanged => write!(f, "mission.status_changed"),
+            WebhookEvents::CrewJoined => write!(f, "crew.joined"),
+        }
+    }
+}
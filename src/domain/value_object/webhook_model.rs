@@ -0,0 +1,133 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{Int4, Jsonb, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::webhooks::{AddWebhookEntity, WebhookDeliveryEntity, WebhookEntity},
+    value_object::webhook_events::WebhookEvents,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookModel {
+    pub id: i32,
+    pub url: String,
+    pub events: Vec<String>,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<WebhookEntity> for WebhookModel {
+    fn from(entity: WebhookEntity) -> Self {
+        Self {
+            id: entity.id,
+            url: entity.url,
+            events: entity.events,
+            is_active: entity.is_active,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+// The signing secret is only ever returned once, when the webhook is registered
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CreatedWebhookModel {
+    pub id: i32,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddWebhookModel {
+    pub url: String,
+    pub events: Vec<WebhookEvents>,
+}
+
+impl AddWebhookModel {
+    pub fn to_entity(&self, owner_id: i32, secret: String) -> AddWebhookEntity {
+        let mut events = self
+            .events
+            .iter()
+            .map(|event| event.to_string())
+            .collect::<Vec<_>>();
+        events.sort();
+        events.dedup();
+
+        AddWebhookEntity {
+            owner_id,
+            url: self.url.trim().to_string(),
+            secret,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookDeliveryModel {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub delivered_at: Option<NaiveDateTime>,
+}
+
+impl From<WebhookDeliveryEntity> for WebhookDeliveryModel {
+    fn from(entity: WebhookDeliveryEntity) -> Self {
+        Self {
+            id: entity.id,
+            webhook_id: entity.webhook_id,
+            event: entity.event,
+            payload: entity.payload,
+            status: entity.status,
+            attempts: entity.attempts,
+            next_attempt_at: entity.next_attempt_at,
+            last_status_code: entity.last_status_code,
+            last_error: entity.last_error,
+            created_at: entity.created_at,
+            delivered_at: entity.delivered_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, QueryableByName)]
+pub struct WebhookDeliveryJobModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub webhook_id: i32,
+    #[diesel(sql_type = Text)]
+    pub url: String,
+    #[diesel(sql_type = Varchar)]
+    pub secret: String,
+    #[diesel(sql_type = Varchar)]
+    pub event: String,
+    #[diesel(sql_type = Jsonb)]
+    pub payload: serde_json::Value,
+    #[diesel(sql_type = Int4)]
+    pub attempts: i32,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionWebhookPayload {
+    pub mission_id: i32,
+    pub name: String,
+    pub status: String,
+    pub chief_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrewWebhookPayload {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+}
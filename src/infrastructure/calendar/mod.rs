@@ -0,0 +1,123 @@
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::{
+    config::config_model::MissionRules,
+    domain::{
+        constants::CALENDAR_DEFAULT_EVENT_MINUTES, value_object::mission_moddel::MissionModel,
+    },
+};
+
+const PRODUCT_ID: &str = "-//crate//missions//EN";
+const MAX_LINE_OCTETS: usize = 75;
+
+// Renders an RFC 5545 calendar; all timestamps are stored (and emitted) in UTC
+pub fn render(calendar_name: &str, missions: &[MissionModel], rules: &MissionRules) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape(calendar_name)),
+    ];
+
+    for mission in missions {
+        lines.extend(event_lines(mission, rules));
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+fn event_lines(mission: &MissionModel, rules: &MissionRules) -> Vec<String> {
+    let start = mission.scheduled_for.unwrap_or(mission.created_at);
+    // A deadline before the start would make an invalid event, so fall back to a fixed length
+    let end = mission
+        .deadline
+        .filter(|deadline| *deadline > start)
+        .unwrap_or(start + TimeDelta::minutes(CALENDAR_DEFAULT_EVENT_MINUTES));
+    let status = if mission.status == "Failed" {
+        "CANCELLED"
+    } else {
+        "CONFIRMED"
+    };
+
+    let mut description = format!(
+        "Status: {}\nChief: {}\nCrew: {}/{}",
+        mission.status, mission.chief_display_name, mission.crew_count, rules.max_crew_per_mission
+    );
+    if let Some(deadline) = mission.deadline {
+        description.push_str(&format!(
+            "\nDeadline: {} UTC",
+            deadline.format("%Y-%m-%d %H:%M")
+        ));
+    }
+    if let Some(text) = mission
+        .description
+        .as_deref()
+        .filter(|text| !text.is_empty())
+    {
+        description.push_str("\n\n");
+        description.push_str(text);
+    }
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:mission-{}@crate", mission.id),
+        format!("DTSTAMP:{}", timestamp(mission.updated_at)),
+        format!("LAST-MODIFIED:{}", timestamp(mission.updated_at)),
+        format!("DTSTART:{}", timestamp(start)),
+        format!("DTEND:{}", timestamp(end)),
+        format!("SUMMARY:{}", escape(&mission.name)),
+        format!("DESCRIPTION:{}", escape(&description)),
+        format!("STATUS:{}", status),
+    ];
+
+    let categories = mission
+        .category
+        .iter()
+        .chain(mission.tags.iter())
+        .map(|category| escape(category))
+        .collect::<Vec<_>>();
+    if !categories.is_empty() {
+        lines.push(format!("CATEGORIES:{}", categories.join(",")));
+    }
+    lines.push("END:VEVENT".to_string());
+
+    lines
+}
+
+fn timestamp(value: NaiveDateTime) -> String {
+    value.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+// Long lines are split on char boundaries and continued with a leading space
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+
+    for ch in line.chars() {
+        if octets + ch.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(ch);
+        octets += ch.len_utf8();
+    }
+
+    folded
+}
@@ -1,4 +1,4 @@
-use crate::config::{config_loader::get_cloudinary_env, config_model::CloudinaryEnv};
+use crate::config::config_model::CloudinaryEnv;
 use crate::domain::value_object::base64_image::Base64Image;
 use crate::domain::value_object::upload_image::UploadedImage;
 use anyhow::{Context, Result};
@@ -62,11 +62,10 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
 pub async fn upload(
     base64_image: Base64Image,
     option: UploadImageOptions,
+    cloud_env: &CloudinaryEnv,
 ) -> Result<UploadedImage> {
-    let cloud_env = get_cloudinary_env()?;
-
     let file = Part::text(base64_image.into_inner());
-    let form = form_builder(option, &cloud_env)?;
+    let form = form_builder(option, cloud_env)?;
     let multipart = form.part("file", file);
     let client = reqwest::Client::new();
     let url = format!(
@@ -86,3 +85,32 @@ pub async fn upload(
         serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
     Ok(json)
 }
+
+// Admin API ping: confirms the cloud exists and the credentials are accepted without uploading
+pub async fn ping(cloud_env: &CloudinaryEnv) -> Result<()> {
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/ping",
+        cloud_env.cloud_name
+    );
+
+    reqwest::Client::new()
+        .get(&url)
+        .basic_auth(&cloud_env.api_key, Some(&cloud_env.api_secret))
+        .send()
+        .await
+        .context(format!("ping {}", url))?
+        .error_for_status()?;
+
+    Ok(())
+}
+
+// Stands in for Cloudinary in Local: the avatar is stored as its own data URL
+pub fn mock_upload(base64_image: Base64Image, option: UploadImageOptions) -> UploadedImage {
+    let public_id = [option.folder, option.public_id]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("/");
+
+    UploadedImage::new(base64_image.into_inner(), format!("mock/{}", public_id))
+}
@@ -0,0 +1,2 @@
+DROP TABLE mission_comments;
+ALTER TABLE missions DROP COLUMN comment_visibility;
@@ -0,0 +1,15 @@
+ALTER TABLE missions ADD COLUMN comment_visibility VARCHAR(20) NOT NULL DEFAULT 'Public';
+
+CREATE TABLE mission_comments (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMP
+);
+
+CREATE INDEX idx_mission_comments_mission ON mission_comments(mission_id, created_at);
+
+SELECT diesel_manage_updated_at('mission_comments');
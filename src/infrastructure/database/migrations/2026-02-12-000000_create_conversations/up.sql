@@ -0,0 +1,39 @@
+CREATE TABLE conversations (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER UNIQUE REFERENCES missions(id) ON DELETE CASCADE,
+    "name" VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE conversation_members (
+    conversation_id INTEGER NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    joined_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    last_read_message_id INTEGER,
+    last_read_at TIMESTAMP,
+    PRIMARY KEY (conversation_id, brawler_id)
+);
+
+CREATE TABLE conversation_messages (
+    id SERIAL PRIMARY KEY,
+    conversation_id INTEGER NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    sender_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_conversation_members_brawler ON conversation_members(brawler_id);
+CREATE INDEX idx_conversation_messages_conversation ON conversation_messages(conversation_id, id);
+
+-- Every existing mission gets its channel, with the chief and current crew as members
+INSERT INTO conversations (mission_id, "name")
+SELECT id, "name" FROM missions WHERE deleted_at IS NULL;
+
+INSERT INTO conversation_members (conversation_id, brawler_id)
+SELECT c.id, m.chief_id
+FROM conversations c
+INNER JOIN missions m ON m.id = c.mission_id
+UNION
+SELECT c.id, cm.brawler_id
+FROM conversations c
+INNER JOIN crew_memberships cm ON cm.mission_id = c.mission_id;
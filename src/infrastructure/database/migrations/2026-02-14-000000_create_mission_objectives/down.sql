@@ -0,0 +1,2 @@
+DROP TABLE mission_objectives;
+ALTER TABLE missions DROP COLUMN require_objectives;
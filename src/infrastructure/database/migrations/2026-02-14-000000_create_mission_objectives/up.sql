@@ -0,0 +1,17 @@
+ALTER TABLE missions ADD COLUMN require_objectives BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE mission_objectives (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    "position" INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    is_mandatory BOOLEAN NOT NULL DEFAULT TRUE,
+    completed_by INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mission_objectives_mission ON mission_objectives(mission_id, "position");
+
+SELECT diesel_manage_updated_at('mission_objectives');
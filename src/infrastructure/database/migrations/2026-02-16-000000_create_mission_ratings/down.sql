@@ -0,0 +1,3 @@
+DROP TABLE crew_departures;
+DROP TABLE mission_ratings;
+ALTER TABLE missions DROP COLUMN finished_at;
@@ -0,0 +1,26 @@
+ALTER TABLE missions ADD COLUMN finished_at TIMESTAMP;
+
+UPDATE missions SET finished_at = updated_at WHERE "status" IN ('Completed', 'Failed');
+
+CREATE TABLE mission_ratings (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    rater_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    ratee_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    score SMALLINT NOT NULL CHECK (score BETWEEN 1 AND 5),
+    "comment" TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE (mission_id, rater_id, ratee_id)
+);
+
+CREATE INDEX idx_mission_ratings_ratee ON mission_ratings(ratee_id);
+
+CREATE TABLE crew_departures (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    mission_status VARCHAR(255) NOT NULL,
+    left_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_crew_departures_brawler ON crew_departures(brawler_id);
@@ -0,0 +1,3 @@
+DROP FUNCTION IF EXISTS brawler_level(BIGINT);
+DROP TABLE brawler_achievements;
+DROP TABLE xp_awards;
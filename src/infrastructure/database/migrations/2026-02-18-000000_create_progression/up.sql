@@ -0,0 +1,23 @@
+CREATE TABLE xp_awards (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    amount INTEGER NOT NULL CHECK (amount > 0),
+    reason VARCHAR(50) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE (brawler_id, mission_id, reason)
+);
+
+CREATE INDEX idx_xp_awards_brawler ON xp_awards(brawler_id);
+
+CREATE TABLE brawler_achievements (
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    achievement VARCHAR(50) NOT NULL,
+    unlocked_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (brawler_id, achievement)
+);
+
+-- Level 1 at 0 XP, level 2 at 100 XP, level 3 at 400 XP, level n at 100 * (n - 1)^2 XP
+CREATE OR REPLACE FUNCTION brawler_level(xp BIGINT) RETURNS INTEGER AS $$
+    SELECT FLOOR(SQRT(GREATEST(xp, 0) / 100.0))::INTEGER + 1;
+$$ LANGUAGE sql IMMUTABLE;
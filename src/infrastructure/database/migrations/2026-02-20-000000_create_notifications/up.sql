@@ -0,0 +1,13 @@
+CREATE TABLE notifications (
+    id SERIAL PRIMARY KEY,
+    recipient_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    actor_id INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    mission_id INTEGER REFERENCES missions(id) ON DELETE CASCADE,
+    kind VARCHAR(50) NOT NULL,
+    content TEXT NOT NULL,
+    read_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_notifications_recipient ON notifications(recipient_id, created_at DESC);
+CREATE INDEX idx_notifications_unread ON notifications(recipient_id) WHERE read_at IS NULL;
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_missions_search_vector;
+ALTER TABLE missions DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,8 @@
+-- Name outweighs description so a keyword in the title ranks above one buried in the text
+ALTER TABLE missions
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', COALESCE(name, '')), 'A') ||
+        setweight(to_tsvector('english', COALESCE(description, '')), 'B')
+    ) STORED;
+
+CREATE INDEX idx_missions_search_vector ON missions USING GIN (search_vector);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_missions_created_at;
+DROP INDEX IF EXISTS idx_missions_deadline;
+ALTER TABLE missions DROP COLUMN IF EXISTS deadline;
@@ -0,0 +1,4 @@
+ALTER TABLE missions ADD COLUMN deadline TIMESTAMP;
+
+CREATE INDEX idx_missions_deadline ON missions(deadline) WHERE deleted_at IS NULL;
+CREATE INDEX idx_missions_created_at ON missions(created_at) WHERE deleted_at IS NULL;
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS mission_tags;
+DROP TABLE IF EXISTS tags;
+
+DROP INDEX IF EXISTS idx_missions_category;
+ALTER TABLE missions DROP COLUMN IF EXISTS category;
@@ -0,0 +1,17 @@
+ALTER TABLE missions ADD COLUMN category VARCHAR(30);
+
+CREATE INDEX idx_missions_category ON missions(category) WHERE deleted_at IS NULL;
+
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(32) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE mission_tags (
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (mission_id, tag_id)
+);
+
+CREATE INDEX idx_mission_tags_tag ON mission_tags(tag_id);
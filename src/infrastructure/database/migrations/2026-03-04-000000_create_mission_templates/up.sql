@@ -0,0 +1,17 @@
+CREATE TABLE mission_templates (
+    id SERIAL PRIMARY KEY,
+    owner_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    comment_visibility VARCHAR(20) NOT NULL DEFAULT 'Public',
+    require_objectives BOOLEAN NOT NULL DEFAULT FALSE,
+    category VARCHAR(30),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    objectives JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mission_templates_owner ON mission_templates(owner_id, created_at DESC);
+
+SELECT diesel_manage_updated_at('mission_templates');
@@ -0,0 +1,20 @@
+CREATE TABLE mission_series (
+    id SERIAL PRIMARY KEY,
+    chief_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    frequency VARCHAR(20) NOT NULL,
+    weekdays INTEGER[] NOT NULL DEFAULT '{}',
+    starts_at TIMESTAMP NOT NULL,
+    next_occurrence_at TIMESTAMP NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mission_series_due ON mission_series(next_occurrence_at) WHERE is_active;
+
+SELECT diesel_manage_updated_at('mission_series');
+
+ALTER TABLE missions ADD COLUMN series_id INTEGER REFERENCES mission_series(id) ON DELETE SET NULL;
+ALTER TABLE missions ADD COLUMN scheduled_for TIMESTAMP;
+
+CREATE INDEX idx_missions_series ON missions(series_id, scheduled_for DESC) WHERE series_id IS NOT NULL;
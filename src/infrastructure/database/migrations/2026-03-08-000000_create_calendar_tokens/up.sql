@@ -0,0 +1,5 @@
+CREATE TABLE calendar_tokens (
+    brawler_id INTEGER PRIMARY KEY REFERENCES brawlers(id) ON DELETE CASCADE,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
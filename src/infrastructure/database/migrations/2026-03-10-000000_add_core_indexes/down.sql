@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_friendships_friend;
+DROP INDEX IF EXISTS idx_crew_memberships_brawler;
+DROP INDEX IF EXISTS idx_missions_status;
+DROP INDEX IF EXISTS idx_missions_chief;
+DROP INDEX IF EXISTS idx_brawlers_username;
@@ -0,0 +1,20 @@
+-- Usernames are meant to be unique, but older databases were never constrained. Where duplicates
+-- already exist the index is created non-unique so startup still succeeds; to finish the job,
+-- rename the duplicates and then run by hand:
+--   DROP INDEX idx_brawlers_username;
+--   CREATE UNIQUE INDEX idx_brawlers_username ON brawlers(username);
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM brawlers GROUP BY username HAVING COUNT(*) > 1) THEN
+        RAISE WARNING 'brawlers.username has duplicates, idx_brawlers_username created non-unique';
+        CREATE INDEX idx_brawlers_username ON brawlers(username);
+    ELSE
+        CREATE UNIQUE INDEX idx_brawlers_username ON brawlers(username);
+    END IF;
+END
+$$;
+
+CREATE INDEX idx_missions_chief ON missions(chief_id) WHERE deleted_at IS NULL;
+CREATE INDEX idx_missions_status ON missions(status) WHERE deleted_at IS NULL;
+CREATE INDEX idx_crew_memberships_brawler ON crew_memberships(brawler_id);
+CREATE INDEX idx_friendships_friend ON friendships(friend_id);
@@ -0,0 +1 @@
+ALTER TABLE crew_departures DROP COLUMN abandoned;
@@ -0,0 +1,10 @@
+ALTER TABLE crew_departures ADD COLUMN abandoned BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Same rule as is_abandonment: left an Open mission with its deadline less than 24 hours away
+UPDATE crew_departures d
+SET abandoned = TRUE
+FROM missions m
+WHERE m.id = d.mission_id
+  AND d.mission_status = 'Open'
+  AND m.deadline IS NOT NULL
+  AND d.left_at >= m.deadline - INTERVAL '24 hours';
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use diesel::migration::MigrationSource;
+use diesel::{PgConnection, pg::Pg};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+
+// Compiled into the binary so a deploy never depends on the migrations directory being around
+pub const MIGRATIONS: EmbeddedMigrations =
+    embed_migrations!("src/infrastructure/database/migrations");
+
+pub fn run_pending(conn: &mut PgConnection) -> Result<Vec<String>> {
+    let applied = conn
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!("Failed to run migrations: {}", e))?;
+
+    Ok(applied.iter().map(|version| version.to_string()).collect())
+}
+
+// The database must be exactly at this build's latest migration: neither behind it nor ahead of it
+pub fn verify(conn: &mut PgConnection) -> Result<()> {
+    let known = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!("Failed to read embedded migrations: {}", e))?
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .collect::<HashSet<_>>();
+
+    let applied = conn
+        .applied_migrations()
+        .map_err(|e| anyhow::anyhow!("Failed to read applied migrations: {}", e))?;
+    let mut unknown = applied
+        .iter()
+        .map(|version| version.to_string())
+        .filter(|version| !known.contains(version))
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        unknown.sort();
+        return Err(anyhow::anyhow!(
+            "Database has migrations this build does not know about: {}",
+            unknown.join(", ")
+        ));
+    }
+
+    let pending = conn
+        .pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!("Failed to read pending migrations: {}", e))?;
+    if !pending.is_empty() {
+        let names = pending
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect::<Vec<_>>();
+        return Err(anyhow::anyhow!(
+            "Database schema is behind this build, pending migrations: {}",
+            names.join(", ")
+        ));
+    }
+
+    Ok(())
+}
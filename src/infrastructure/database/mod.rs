@@ -1,3 +1,5 @@
+pub mod migrator;
 pub mod repositories;
 pub mod postgresql_connection;
+#[allow(non_snake_case)]
 pub mod schema;
\ No newline at end of file
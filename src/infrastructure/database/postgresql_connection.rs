@@ -1,13 +1,72 @@
-use anyhow::Result;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
 use diesel::{
     prelude::*,
-    r2d2::{ConnectionManager, Pool},
+    r2d2::{ConnectionManager, CustomizeConnection, Pool},
 };
+use tokio::task;
+
+use crate::config::config_model::Database;
 
 pub type PgPoolSquad = Pool<ConnectionManager<PgConnection>>;
 
-pub fn establish_connection(database_url: &str) -> Result<PgPoolSquad> {
-    let manager = ConnectionManager::<PgConnection>::new(database_url);
-    let pool = Pool::builder().build(manager)?;
+pub fn establish_connection(database: &Database) -> Result<PgPoolSquad> {
+    let manager = ConnectionManager::<PgConnection>::new(&database.url);
+    let pool = Pool::builder()
+        .max_size(database.max_connections)
+        .min_idle(database.min_idle)
+        .connection_timeout(Duration::from_secs(database.connection_timeout_seconds))
+        .idle_timeout(Some(Duration::from_secs(database.idle_timeout_seconds)))
+        .connection_customizer(Box::new(StatementTimeout(
+            database.statement_timeout_millis,
+        )))
+        .build(manager)?;
     Ok(pool)
 }
+
+// Caps every statement server-side so a runaway query gives its connection back to the pool
+#[derive(Debug)]
+struct StatementTimeout(u64);
+
+impl CustomizeConnection<PgConnection, diesel::r2d2::Error> for StatementTimeout {
+    fn on_acquire(&self, conn: &mut PgConnection) -> Result<(), diesel::r2d2::Error> {
+        if self.0 == 0 {
+            return Ok(());
+        }
+
+        diesel::sql_query(format!("SET statement_timeout = {}", self.0))
+            .execute(conn)
+            .map(|_| ())
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+// Diesel is synchronous, so every query runs on Tokio's blocking pool instead of parking a runtime worker
+pub trait PgPoolRunner {
+    fn run<R, F>(&self, f: F) -> impl Future<Output = Result<R>> + Send
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut PgConnection) -> Result<R> + Send + 'static;
+}
+
+impl PgPoolRunner for PgPoolSquad {
+    fn run<R, F>(&self, f: F) -> impl Future<Output = Result<R>> + Send
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut PgConnection) -> Result<R> + Send + 'static,
+    {
+        let pool = self.clone();
+
+        async move {
+            task::spawn_blocking(move || {
+                let mut conn = pool
+                    .get()
+                    .context("Failed to get database connection from pool")?;
+                f(&mut conn)
+            })
+            .await
+            .context("Database task failed")?
+        }
+    }
+}
@@ -7,17 +7,19 @@ use diesel::{
 use std::sync::Arc;
 
 use crate::{
+    config::config_model::ImageBackend,
     domain::{
         entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
         repositories::brawlers::BrawlerRepository,
         value_object::{
-            base64_image::Base64Image, mission_moddel::MissionModel, upload_image::UploadedImage,
+            base64_image::Base64Image, brawler_model::BrawlerProfileModel,
+            mission_moddel::MissionModel, upload_image::UploadedImage,
         },
     },
     infrastructure::{
-        cloudinary::UploadImageOptions,
+        cloudinary::{self, UploadImageOptions},
         database::{
-            postgresql_connection::PgPoolSquad,
+            postgresql_connection::{PgPoolRunner, PgPoolSquad},
             schema::{brawlers, crew_memberships},
         },
     },
@@ -36,62 +38,93 @@ impl BrawlerPostgres {
 #[async_trait]
 impl BrawlerRepository for BrawlerPostgres {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+        self.db_pool
+            .run(move |connection| {
+                let result = insert_into(brawlers::table)
+                    .values(&register_brawler_entity)
+                    .returning(brawlers::id)
+                    .get_result::<i32>(connection)?;
 
-        let result = insert_into(brawlers::table)
-            .values(&register_brawler_entity)
-            .returning(brawlers::id)
-            .get_result::<i32>(&mut connection)?;
-
-        Ok(result)
+                Ok(result)
+            })
+            .await
     }
 
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity> {
+        let username = username.to_string();
 
-        let result = brawlers::table
-            .filter(brawlers::username.eq(username))
-            .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)?;
+        self.db_pool
+            .run(move |connection| {
+                let result = brawlers::table
+                    .filter(brawlers::username.eq(username))
+                    .select(BrawlerEntity::as_select())
+                    .first::<BrawlerEntity>(connection)?;
 
-        Ok(result)
+                Ok(result)
+            })
+            .await
     }
     async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
-
-        let result = brawlers::table
-            .filter(brawlers::id.eq(brawler_id))
-            .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)?;
+        self.db_pool
+            .run(move |connection| {
+                let result = brawlers::table
+                    .filter(brawlers::id.eq(brawler_id))
+                    .select(BrawlerEntity::as_select())
+                    .first::<BrawlerEntity>(connection)?;
 
-        Ok(result)
+                Ok(result)
+            })
+            .await
     }
 
     async fn find_by_name_and_tag(&self, name: &str, tag: &str) -> Result<Option<BrawlerEntity>> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+        let (name, tag) = (name.to_string(), tag.to_string());
 
-        let result = brawlers::table
-            .filter(brawlers::display_name.ilike(name))
-            .filter(brawlers::tag.eq(tag))
-            .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)
-            .optional()?;
+        self.db_pool
+            .run(move |connection| {
+                let result = brawlers::table
+                    .filter(brawlers::display_name.ilike(name))
+                    .filter(brawlers::tag.eq(tag))
+                    .select(BrawlerEntity::as_select())
+                    .first::<BrawlerEntity>(connection)
+                    .optional()?;
 
-        Ok(result)
+                Ok(result)
+            })
+            .await
     }
 
     async fn update_name(&self, brawler_id: i32, new_name: String) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+        self.db_pool
+            .run(move |conn| {
+                diesel::update(brawlers::table)
+                    .filter(brawlers::id.eq(brawler_id))
+                    .set((
+                        brawlers::display_name.eq(new_name),
+                        brawlers::name_updated_at.eq(chrono::Utc::now().naive_utc()),
+                    ))
+                    .execute(conn)?;
+
+                Ok(())
+            })
+            .await
+    }
 
-        diesel::update(brawlers::table)
-            .filter(brawlers::id.eq(brawler_id))
-            .set((
-                brawlers::display_name.eq(new_name),
-                brawlers::name_updated_at.eq(chrono::Utc::now().naive_utc()),
-            ))
-            .execute(&mut conn)?;
+    async fn update_password(&self, brawler_id: i32, hashed_password: String) -> Result<()> {
+        self.db_pool
+            .run(move |conn| {
+                let updated = diesel::update(brawlers::table)
+                    .filter(brawlers::id.eq(brawler_id))
+                    .set(brawlers::password.eq(hashed_password))
+                    .execute(conn)?;
 
-        Ok(())
+                if updated == 0 {
+                    return Err(anyhow::anyhow!("Brawler {} not found", brawler_id));
+                }
+
+                Ok(())
+            })
+            .await
     }
 
     async fn upload_base64image(
@@ -99,64 +132,166 @@ impl BrawlerRepository for BrawlerPostgres {
         brawler_id: i32,
         base64_image: Base64Image,
         option: UploadImageOptions,
+        image_backend: ImageBackend,
     ) -> Result<UploadedImage> {
-        let uploaded_image =
-            crate::infrastructure::cloudinary::upload(base64_image, option).await?;
-
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let uploaded_image = match image_backend {
+            ImageBackend::Cloudinary(cloudinary_env) => {
+                cloudinary::upload(base64_image, option, &cloudinary_env).await?
+            }
+            ImageBackend::Mock => cloudinary::mock_upload(base64_image, option),
+            ImageBackend::Disabled => {
+                return Err(anyhow::anyhow!("Image uploads are not configured"));
+            }
+        };
 
-        diesel::update(brawlers::table)
-            .filter(brawlers::id.eq(brawler_id))
-            .set((
-                brawlers::avatar_url.eq(uploaded_image.url.clone()),
-                brawlers::avatar_public_id.eq(uploaded_image.public_id.clone()),
-            ))
-            .execute(&mut conn)?;
+        self.db_pool
+            .run(move |conn| {
+                diesel::update(brawlers::table)
+                    .filter(brawlers::id.eq(brawler_id))
+                    .set((
+                        brawlers::avatar_url.eq(uploaded_image.url.clone()),
+                        brawlers::avatar_public_id.eq(uploaded_image.public_id.clone()),
+                    ))
+                    .execute(conn)?;
 
-        Ok(uploaded_image)
+                Ok(uploaded_image)
+            })
+            .await
     }
 
     async fn crew_counting(&self, brawler_id: i32) -> Result<u32> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
-
-        let result = crew_memberships::table
-            .filter(crew_memberships::brawler_id.eq(brawler_id))
-            .count()
-            .get_result::<i64>(&mut connection)?;
+        self.db_pool
+            .run(move |connection| {
+                let result = crew_memberships::table
+                    .filter(crew_memberships::brawler_id.eq(brawler_id))
+                    .count()
+                    .get_result::<i64>(connection)?;
 
-        Ok(result as u32)
+                Ok(result as u32)
+            })
+            .await
     }
 
     async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        let sql = r#"
-            SELECT 
-                m.id, 
-                m.name, 
-                m.description, 
-                m.status, 
-                m.chief_id, 
-                b.display_name as chief_display_name,
-                COALESCE(cc.crew_count, 0) as crew_count,
-                m.created_at, 
-                m.updated_at
-            FROM missions m
-            INNER JOIN brawlers b ON b.id = m.chief_id
-            INNER JOIN crew_memberships cm ON cm.mission_id = m.id
-            LEFT JOIN (
-                SELECT mission_id, COUNT(*) as crew_count 
-                FROM crew_memberships 
-                GROUP BY mission_id
-            ) cc ON cc.mission_id = m.id
-            WHERE cm.brawler_id = $1 AND m.deleted_at IS NULL
-            ORDER BY m.created_at DESC
-        "#;
-
-        let results = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Int4, _>(brawler_id)
-            .load::<MissionModel>(&mut conn)?;
-
-        Ok(results)
+        self.db_pool
+            .run(move |conn| {
+                let sql = r#"
+                    SELECT 
+                        m.id, 
+                        m.name, 
+                        m.description, 
+                        m.status, 
+                        m.chief_id, 
+                        b.display_name as chief_display_name,
+                        COALESCE(cc.crew_count, 0) as crew_count,
+                        m.comment_visibility,
+                        m.require_objectives,
+                        COALESCE(
+                            100.0 * oc.completed_count / NULLIF(oc.objective_count, 0),
+                            0
+                        )::FLOAT8 as completion_percentage,
+                        m.created_at, 
+                        m.updated_at,
+                        m.finished_at,
+                        m.deadline,
+                        m.category,
+                        ARRAY(
+                            SELECT t.name FROM mission_tags mt
+                            INNER JOIN tags t ON t.id = mt.tag_id
+                            WHERE mt.mission_id = m.id
+                            ORDER BY t.name
+                        )::TEXT[] as tags,
+                        m.series_id,
+                        m.scheduled_for
+                    FROM missions m
+                    INNER JOIN brawlers b ON b.id = m.chief_id
+                    INNER JOIN crew_memberships cm ON cm.mission_id = m.id
+                    LEFT JOIN (
+                        SELECT mission_id, COUNT(*) as crew_count 
+                        FROM crew_memberships 
+                        GROUP BY mission_id
+                    ) cc ON cc.mission_id = m.id
+                    LEFT JOIN (
+                        SELECT mission_id, COUNT(*) as objective_count, COUNT(completed_at) as completed_count
+                        FROM mission_objectives
+                        GROUP BY mission_id
+                    ) oc ON oc.mission_id = m.id
+                    WHERE cm.brawler_id = $1 AND m.deleted_at IS NULL
+                    ORDER BY m.created_at DESC
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .load::<MissionModel>(conn)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn get_profile(&self, brawler_id: i32) -> Result<BrawlerProfileModel> {
+        self.db_pool
+            .run(move |conn| {
+                let sql = r#"
+                    SELECT
+                        b.id,
+                        b.display_name,
+                        b.tag,
+                        b.avatar_url,
+                        (
+                            SELECT COUNT(*)
+                            FROM crew_memberships cm
+                            INNER JOIN missions m ON m.id = cm.mission_id
+                            WHERE cm.brawler_id = b.id AND m.status = 'Completed'
+                        ) AS mission_success_count,
+                        (
+                            SELECT COUNT(*)
+                            FROM crew_memberships cm
+                            WHERE cm.brawler_id = b.id
+                        ) AS mission_joined_count,
+                        (
+                            SELECT COUNT(*)
+                            FROM missions m
+                            WHERE m.chief_id = b.id AND m.deleted_at IS NULL
+                        ) AS mission_led_count,
+                        (
+                            SELECT AVG(r.score)::FLOAT8
+                            FROM mission_ratings r
+                            WHERE r.ratee_id = b.id
+                        ) AS rating_average,
+                        (
+                            SELECT COUNT(*)
+                            FROM mission_ratings r
+                            WHERE r.ratee_id = b.id
+                        ) AS rating_count,
+                        (
+                            SELECT COUNT(*)
+                            FROM crew_departures d
+                            WHERE d.brawler_id = b.id AND d.abandoned
+                        ) AS abandoned_count,
+                        x.xp,
+                        brawler_level(x.xp) AS level,
+                        ARRAY(
+                            SELECT ba.achievement::TEXT
+                            FROM brawler_achievements ba
+                            WHERE ba.brawler_id = b.id
+                            ORDER BY ba.unlocked_at
+                        ) AS achievements
+                    FROM brawlers b
+                    CROSS JOIN LATERAL (
+                        SELECT COALESCE(SUM(amount), 0)::BIGINT AS xp
+                        FROM xp_awards
+                        WHERE brawler_id = b.id
+                    ) x
+                    WHERE b.id = $1
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .get_result::<BrawlerProfileModel>(conn)?;
+
+                Ok(result)
+            })
+            .await
     }
 }
@@ -0,0 +1,130 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{dsl::now, prelude::*, upsert::excluded};
+use std::sync::Arc;
+
+use crate::domain::repositories::calendar::CalendarRepository;
+use crate::domain::value_object::mission_moddel::MissionModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::calendar_tokens;
+
+pub struct CalendarPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl CalendarPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl CalendarRepository for CalendarPostgres {
+    async fn save_token_hash(&self, brawler_id: i32, token_hash: String) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::insert_into(calendar_tokens::table)
+                    .values((
+                        calendar_tokens::brawler_id.eq(brawler_id),
+                        calendar_tokens::token_hash.eq(token_hash),
+                    ))
+                    .on_conflict(calendar_tokens::brawler_id)
+                    .do_update()
+                    .set((
+                        calendar_tokens::token_hash.eq(excluded(calendar_tokens::token_hash)),
+                        calendar_tokens::created_at.eq(now),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn revoke_token(&self, brawler_id: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::delete(calendar_tokens::table)
+                    .filter(calendar_tokens::brawler_id.eq(brawler_id))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn find_brawler_by_token_hash(&self, token_hash: String) -> Result<Option<i32>> {
+        self.db_pool
+            .run(move |connection| {
+                let result = calendar_tokens::table
+                    .filter(calendar_tokens::token_hash.eq(token_hash))
+                    .select(calendar_tokens::brawler_id)
+                    .first::<i32>(connection)
+                    .optional()?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn missions_of(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+        self.db_pool
+            .run(move |conn| {
+                let sql = r#"
+                    SELECT 
+                        m.id, 
+                        m.name, 
+                        m.description, 
+                        m.status, 
+                        m.chief_id, 
+                        b.display_name as chief_display_name,
+                        COALESCE(cc.crew_count, 0) as crew_count,
+                        m.comment_visibility,
+                        m.require_objectives,
+                        COALESCE(
+                            100.0 * oc.completed_count / NULLIF(oc.objective_count, 0),
+                            0
+                        )::FLOAT8 as completion_percentage,
+                        m.created_at, 
+                        m.updated_at,
+                        m.finished_at,
+                        m.deadline,
+                        m.category,
+                        ARRAY(
+                            SELECT t.name FROM mission_tags mt
+                            INNER JOIN tags t ON t.id = mt.tag_id
+                            WHERE mt.mission_id = m.id
+                            ORDER BY t.name
+                        )::TEXT[] as tags,
+                        m.series_id,
+                        m.scheduled_for
+                    FROM missions m
+                    INNER JOIN brawlers b ON b.id = m.chief_id
+                    LEFT JOIN (
+                        SELECT mission_id, COUNT(*) as crew_count 
+                        FROM crew_memberships 
+                        GROUP BY mission_id
+                    ) cc ON cc.mission_id = m.id
+                    LEFT JOIN (
+                        SELECT mission_id, COUNT(*) as objective_count, COUNT(completed_at) as completed_count
+                        FROM mission_objectives
+                        GROUP BY mission_id
+                    ) oc ON oc.mission_id = m.id
+                    WHERE m.deleted_at IS NULL AND (
+                        m.chief_id = $1 OR EXISTS (
+                            SELECT 1 FROM crew_memberships cm
+                            WHERE cm.mission_id = m.id AND cm.brawler_id = $1
+                        )
+                    )
+                    ORDER BY COALESCE(m.scheduled_for, m.created_at) DESC
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .load::<MissionModel>(conn)?;
+
+                Ok(results)
+            })
+            .await
+    }
+}
@@ -0,0 +1,298 @@
+use crate::domain::entities::conversations::{
+    AddConversationEntity, ConversationEntity, ConversationMemberEntity, ConversationMessage,
+};
+use crate::domain::repositories::conversations::ConversationRepository;
+use crate::domain::value_object::conversation_model::ConversationSummaryModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{
+    conversation_members, conversation_messages, conversations, crew_memberships,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+pub struct ConversationPostgres {
+    pool: Arc<PgPoolSquad>,
+}
+
+impl ConversationPostgres {
+    pub fn new(pool: Arc<PgPoolSquad>) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = conversation_messages)]
+pub struct ConversationMessageDb {
+    pub id: i32,
+    pub conversation_id: i32,
+    pub sender_id: i32,
+    pub content: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<ConversationMessageDb> for ConversationMessage {
+    fn from(db: ConversationMessageDb) -> Self {
+        ConversationMessage {
+            id: db.id,
+            conversation_id: db.conversation_id,
+            sender_id: db.sender_id,
+            content: db.content,
+            created_at: db.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl ConversationRepository for ConversationPostgres {
+    async fn create_mission_channel(
+        &self,
+        mission_id: i32,
+        name: String,
+        chief_id: i32,
+    ) -> Result<i32> {
+        self.pool
+            .run(move |conn| {
+                conn.transaction::<i32, anyhow::Error, _>(|conn| {
+                    let inserted = diesel::insert_into(conversations::table)
+                        .values(&AddConversationEntity {
+                            mission_id: Some(mission_id),
+                            name,
+                        })
+                        .on_conflict(conversations::mission_id)
+                        .do_nothing()
+                        .returning(conversations::id)
+                        .get_result::<i32>(conn)
+                        .optional()
+                        .context("Error creating mission channel")?;
+
+                    let conversation_id = match inserted {
+                        Some(id) => id,
+                        None => conversations::table
+                            .filter(conversations::mission_id.eq(mission_id))
+                            .select(conversations::id)
+                            .first::<i32>(conn)
+                            .context("Error loading mission channel")?,
+                    };
+
+                    diesel::insert_into(conversation_members::table)
+                        .values(&ConversationMemberEntity {
+                            conversation_id,
+                            brawler_id: chief_id,
+                        })
+                        .on_conflict_do_nothing()
+                        .execute(conn)
+                        .context("Error adding chief to mission channel")?;
+
+                    Ok(conversation_id)
+                })
+            })
+            .await
+    }
+
+    async fn find_by_id(&self, conversation_id: i32) -> Result<ConversationEntity> {
+        self.pool
+            .run(move |conn| {
+                let result = conversations::table
+                    .filter(conversations::id.eq(conversation_id))
+                    .select(ConversationEntity::as_select())
+                    .first::<ConversationEntity>(conn)
+                    .context("Channel not found")?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn find_by_mission(&self, mission_id: i32) -> Result<Option<ConversationEntity>> {
+        self.pool
+            .run(move |conn| {
+                let result = conversations::table
+                    .filter(conversations::mission_id.eq(mission_id))
+                    .select(ConversationEntity::as_select())
+                    .first::<ConversationEntity>(conn)
+                    .optional()
+                    .context("Error loading mission channel")?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn sync_crew_member(
+        &self,
+        conversation_id: i32,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<()> {
+        self.pool
+            .run(move |conn| {
+                conn.transaction(|conn| {
+                    // Any join or leave committed after this read stages its own event, which
+                    // syncs the channel again
+                    let is_crew = crew_memberships::table
+                        .filter(crew_memberships::mission_id.eq(mission_id))
+                        .filter(crew_memberships::brawler_id.eq(brawler_id))
+                        .select(crew_memberships::brawler_id)
+                        .for_update()
+                        .first::<i32>(conn)
+                        .optional()
+                        .context("Error checking crew membership")?
+                        .is_some();
+
+                    if is_crew {
+                        diesel::insert_into(conversation_members::table)
+                            .values(&ConversationMemberEntity {
+                                conversation_id,
+                                brawler_id,
+                            })
+                            .on_conflict_do_nothing()
+                            .execute(conn)
+                            .context("Error adding channel member")?;
+                    } else {
+                        diesel::delete(conversation_members::table)
+                            .filter(conversation_members::conversation_id.eq(conversation_id))
+                            .filter(conversation_members::brawler_id.eq(brawler_id))
+                            .execute(conn)
+                            .context("Error removing channel member")?;
+                    }
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+
+    async fn is_member(&self, conversation_id: i32, brawler_id: i32) -> Result<bool> {
+        self.pool
+            .run(move |conn| {
+                let count = conversation_members::table
+                    .filter(conversation_members::conversation_id.eq(conversation_id))
+                    .filter(conversation_members::brawler_id.eq(brawler_id))
+                    .count()
+                    .get_result::<i64>(conn)
+                    .context("Error checking channel membership")?;
+
+                Ok(count > 0)
+            })
+            .await
+    }
+
+    async fn get_channels(&self, brawler_id: i32) -> Result<Vec<ConversationSummaryModel>> {
+        self.pool
+            .run(move |conn| {
+                let sql = r#"
+                    SELECT
+                        c.id,
+                        c.mission_id,
+                        c.name,
+                        (
+                            SELECT COUNT(*)
+                            FROM conversation_members cm2
+                            WHERE cm2.conversation_id = c.id
+                        ) AS member_count,
+                        (
+                            SELECT COUNT(*)
+                            FROM conversation_messages msg
+                            WHERE msg.conversation_id = c.id
+                                AND msg.sender_id <> cm.brawler_id
+                                AND msg.id > COALESCE(cm.last_read_message_id, 0)
+                        ) AS unread_count,
+                        (
+                            SELECT MAX(msg.created_at)
+                            FROM conversation_messages msg
+                            WHERE msg.conversation_id = c.id
+                        ) AS last_message_at
+                    FROM conversation_members cm
+                    INNER JOIN conversations c ON c.id = cm.conversation_id
+                    WHERE cm.brawler_id = $1
+                    ORDER BY last_message_at DESC NULLS LAST, c.created_at DESC
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .load::<ConversationSummaryModel>(conn)
+                    .context("Error loading channels")?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn send_message(
+        &self,
+        conversation_id: i32,
+        sender_id: i32,
+        content: String,
+    ) -> Result<ConversationMessage> {
+        self.pool
+            .run(move |conn| {
+                #[derive(Insertable)]
+                #[diesel(table_name = conversation_messages)]
+                struct NewConversationMessage {
+                    conversation_id: i32,
+                    sender_id: i32,
+                    content: String,
+                }
+
+                let new_msg = NewConversationMessage {
+                    conversation_id,
+                    sender_id,
+                    content,
+                };
+
+                let result: ConversationMessageDb =
+                    diesel::insert_into(conversation_messages::table)
+                        .values(&new_msg)
+                        .get_result(conn)
+                        .context("Error saving new channel message")?;
+
+                Ok(ConversationMessage::from(result))
+            })
+            .await
+    }
+
+    async fn get_messages(&self, conversation_id: i32) -> Result<Vec<ConversationMessage>> {
+        self.pool
+            .run(move |conn| {
+                let results: Vec<ConversationMessageDb> = conversation_messages::table
+                    .filter(conversation_messages::conversation_id.eq(conversation_id))
+                    .order(conversation_messages::id.asc())
+                    .load::<ConversationMessageDb>(conn)
+                    .context("Error loading channel messages")?;
+
+                Ok(results.into_iter().map(ConversationMessage::from).collect())
+            })
+            .await
+    }
+
+    async fn mark_as_read(&self, conversation_id: i32, brawler_id: i32) -> Result<()> {
+        self.pool
+            .run(move |conn| {
+                let last_message_id = conversation_messages::table
+                    .filter(conversation_messages::conversation_id.eq(conversation_id))
+                    .select(diesel::dsl::max(conversation_messages::id))
+                    .first::<Option<i32>>(conn)
+                    .context("Error loading latest channel message")?;
+
+                let Some(last_message_id) = last_message_id else {
+                    return Ok(());
+                };
+
+                diesel::update(conversation_members::table)
+                    .filter(conversation_members::conversation_id.eq(conversation_id))
+                    .filter(conversation_members::brawler_id.eq(brawler_id))
+                    .set((
+                        conversation_members::last_read_message_id.eq(last_message_id),
+                        conversation_members::last_read_at.eq(diesel::dsl::now),
+                    ))
+                    .execute(conn)
+                    .context("Error marking channel as read")?;
+
+                Ok(())
+            })
+            .await
+    }
+}
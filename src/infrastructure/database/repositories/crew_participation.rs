@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
 use diesel::PgConnection;
 use diesel::RunQueryDsl;
 use diesel::delete;
@@ -8,13 +9,49 @@ use std::sync::Arc;
 
 use crate::domain::entities::crew_memberships::CrewMemberShips;
 use crate::domain::repositories::crew_oparation::CrewOperationRepository;
-use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
-use crate::infrastructure::database::schema::crew_memberships;
+use crate::domain::value_object::mission_rating_model::is_abandonment;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{crew_departures, crew_memberships, missions};
 
 pub struct CrewParticipationPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
 
+fn insert_membership(conn: &mut PgConnection, crew_memberships: CrewMemberShips) -> Result<()> {
+    diesel::insert_into(crew_memberships::table)
+        .values(&crew_memberships)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+fn remove_membership(conn: &mut PgConnection, crew_memberships: CrewMemberShips) -> Result<()> {
+    let removed = diesel::delete(crew_memberships::table)
+        .filter(crew_memberships::brawler_id.eq(crew_memberships.brawler_id))
+        .filter(crew_memberships::mission_id.eq(crew_memberships.mission_id))
+        .execute(conn)?;
+
+    if removed > 0 {
+        let (mission_status, deadline) = missions::table
+            .filter(missions::id.eq(crew_memberships.mission_id))
+            .select((missions::status, missions::deadline))
+            .first::<(String, Option<NaiveDateTime>)>(conn)?;
+        let left_at = Utc::now().naive_utc();
+
+        diesel::insert_into(crew_departures::table)
+            .values((
+                crew_departures::mission_id.eq(crew_memberships.mission_id),
+                crew_departures::brawler_id.eq(crew_memberships.brawler_id),
+                crew_departures::abandoned.eq(is_abandonment(&mission_status, deadline, left_at)),
+                crew_departures::mission_status.eq(mission_status),
+                crew_departures::left_at.eq(left_at),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
+
 impl CrewParticipationPostgres {
     pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
         Self { db_pool }
@@ -24,30 +61,35 @@ impl CrewParticipationPostgres {
 #[async_trait]
 impl CrewOperationRepository for CrewParticipationPostgres {
     async fn join(&self, crew_memberships: CrewMemberShips) -> Result<()> {
-        let mut connection = self
-            .db_pool
-            .get()
-            .map_err(|e| anyhow::Error::msg(e.to_string()))?;
-
-        diesel::insert_into(crew_memberships::table)
-            .values(&crew_memberships)
-            .execute(&mut connection)?;
-
-        Ok(())
+        self.db_pool
+            .run(move |connection| insert_membership(connection, crew_memberships))
+            .await
     }
 
     async fn leave(&self, crew_memberships: CrewMemberShips) -> Result<()> {
-        let mut connection = self
-            .db_pool
-            .get()
-            .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+        self.db_pool
+            .run(move |connection| {
+                connection.transaction::<(), anyhow::Error, _>(|conn| {
+                    remove_membership(conn, crew_memberships)
+                })
+            })
+            .await
+    }
 
-        diesel::delete(crew_memberships::table)
-            .filter(crew_memberships::brawler_id.eq(crew_memberships.brawler_id))
-            .filter(crew_memberships::mission_id.eq(crew_memberships.mission_id))
-            .execute(&mut connection)?;
+    fn join_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        crew_memberships: CrewMemberShips,
+    ) -> Result<()> {
+        insert_membership(conn, crew_memberships)
+    }
 
-        Ok(())
+    fn leave_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        crew_memberships: CrewMemberShips,
+    ) -> Result<()> {
+        remove_membership(conn, crew_memberships)
     }
 
     fn for_insert_transaction_test(
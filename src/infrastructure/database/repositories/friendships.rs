@@ -1,8 +1,8 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use diesel::{
-    ExpressionMethods, JoinOnDsl, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
-    insert_into,
+    ExpressionMethods, JoinOnDsl, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper, insert_into,
 };
 use std::sync::Arc;
 
@@ -15,7 +15,7 @@ use crate::{
         repositories::friendships::FriendshipRepository,
     },
     infrastructure::database::{
-        postgresql_connection::PgPoolSquad,
+        postgresql_connection::{PgPoolRunner, PgPoolSquad},
         schema::{brawlers, friendships},
     },
 };
@@ -24,6 +24,14 @@ pub struct FriendshipPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
 
+fn insert_friendship(conn: &mut PgConnection, friendship: NewFriendshipEntity) -> Result<()> {
+    insert_into(friendships::table)
+        .values(&friendship)
+        .execute(conn)?;
+
+    Ok(())
+}
+
 impl FriendshipPostgres {
     pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
         Self { db_pool }
@@ -33,13 +41,17 @@ impl FriendshipPostgres {
 #[async_trait]
 impl FriendshipRepository for FriendshipPostgres {
     async fn add_friend(&self, friendship: NewFriendshipEntity) -> Result<()> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
-
-        insert_into(friendships::table)
-            .values(&friendship)
-            .execute(&mut connection)?;
+        self.db_pool
+            .run(move |connection| insert_friendship(connection, friendship))
+            .await
+    }
 
-        Ok(())
+    fn add_friend_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        friendship: NewFriendshipEntity,
+    ) -> Result<()> {
+        insert_friendship(conn, friendship)
     }
 
     async fn find_friendship(
@@ -47,28 +59,32 @@ impl FriendshipRepository for FriendshipPostgres {
         brawler_id: i32,
         friend_id: i32,
     ) -> Result<Option<FriendshipEntity>> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+        self.db_pool
+            .run(move |connection| {
+                let result = friendships::table
+                    .filter(friendships::brawler_id.eq(brawler_id))
+                    .filter(friendships::friend_id.eq(friend_id))
+                    .select(FriendshipEntity::as_select())
+                    .first::<FriendshipEntity>(connection)
+                    .optional()?;
 
-        let result = friendships::table
-            .filter(friendships::brawler_id.eq(brawler_id))
-            .filter(friendships::friend_id.eq(friend_id))
-            .select(FriendshipEntity::as_select())
-            .first::<FriendshipEntity>(&mut connection)
-            .optional()?;
-
-        Ok(result)
+                Ok(result)
+            })
+            .await
     }
 
     async fn get_friends(&self, brawler_id: i32) -> Result<Vec<BrawlerEntity>> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
-
-        let friends = brawlers::table
-            .inner_join(friendships::table.on(friendships::friend_id.eq(brawlers::id)))
-            .filter(friendships::brawler_id.eq(brawler_id))
-            // .filter(friendships::status.eq("accepted")) // For now, we'll just get all contacts if simple add friend
-            .select(BrawlerEntity::as_select())
-            .load::<BrawlerEntity>(&mut connection)?;
+        self.db_pool
+            .run(move |connection| {
+                let friends = brawlers::table
+                    .inner_join(friendships::table.on(friendships::friend_id.eq(brawlers::id)))
+                    .filter(friendships::brawler_id.eq(brawler_id))
+                    // .filter(friendships::status.eq("accepted")) // For now, we'll just get all contacts if simple add friend
+                    .select(BrawlerEntity::as_select())
+                    .load::<BrawlerEntity>(connection)?;
 
-        Ok(friends)
+                Ok(friends)
+            })
+            .await
     }
 }
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{Connection, prelude::*};
+use std::sync::Arc;
+
+use crate::domain::repositories::maintenance::MaintenanceRepository;
+use crate::domain::value_object::maintenance_model::PurgeReportModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{crew_memberships, mission_comments, missions};
+
+pub struct MaintenancePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MaintenancePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MaintenanceRepository for MaintenancePostgres {
+    async fn purge_deleted(&self, deleted_before: NaiveDateTime) -> Result<PurgeReportModel> {
+        self.db_pool
+            .run(move |connection| {
+                connection.transaction::<PurgeReportModel, anyhow::Error, _>(|conn| {
+                    let comments = diesel::delete(mission_comments::table)
+                        .filter(mission_comments::deleted_at.lt(deleted_before))
+                        .execute(conn)?;
+
+                    let purged_missions = missions::table
+                        .filter(missions::deleted_at.lt(deleted_before))
+                        .select(missions::id);
+
+                    // Crew memberships are the only mission reference without ON DELETE CASCADE
+                    diesel::delete(crew_memberships::table)
+                        .filter(crew_memberships::mission_id.eq_any(purged_missions))
+                        .execute(conn)?;
+
+                    let missions = diesel::delete(missions::table)
+                        .filter(missions::deleted_at.lt(deleted_before))
+                        .execute(conn)?;
+
+                    Ok(PurgeReportModel { missions, comments })
+                })
+            })
+            .await
+    }
+}
@@ -1,17 +1,45 @@
 use crate::domain::entities::messages::Message;
 use crate::domain::repositories::messages::MessageRepository;
-use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
 use crate::infrastructure::database::schema::messages;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::NaiveDateTime;
-use diesel::prelude::*;
+use diesel::{PgConnection, prelude::*};
 use std::sync::Arc;
 
 pub struct MessagePostgres {
     pool: Arc<PgPoolSquad>,
 }
 
+fn insert_message(
+    conn: &mut PgConnection,
+    sender_id: i32,
+    receiver_id: i32,
+    content: String,
+) -> Result<Message> {
+    #[derive(Insertable)]
+    #[diesel(table_name = messages)]
+    struct NewMessage {
+        sender_id: i32,
+        receiver_id: i32,
+        content: String,
+    }
+
+    let new_msg = NewMessage {
+        sender_id,
+        receiver_id,
+        content,
+    };
+
+    let result: MessageDb = diesel::insert_into(messages::table)
+        .values(&new_msg)
+        .get_result(conn)
+        .context("Error saving new message")?;
+
+    Ok(Message::from(result))
+}
+
 impl MessagePostgres {
     pub fn new(pool: Arc<PgPoolSquad>) -> Self {
         Self { pool }
@@ -50,60 +78,55 @@ impl MessageRepository for MessagePostgres {
         receiver_id: i32,
         content: String,
     ) -> Result<Message> {
-        let mut conn = self.pool.get().context("Failed to get DB connection")?;
-
-        #[derive(Insertable)]
-        #[diesel(table_name = messages)]
-        struct NewMessage {
-            sender_id: i32,
-            receiver_id: i32,
-            content: String,
-        }
-
-        let new_msg = NewMessage {
-            sender_id,
-            receiver_id,
-            content,
-        };
-
-        let result: MessageDb = diesel::insert_into(messages::table)
-            .values(&new_msg)
-            .get_result(&mut conn)
-            .context("Error saving new message")?;
+        self.pool
+            .run(move |conn| insert_message(conn, sender_id, receiver_id, content))
+            .await
+    }
 
-        Ok(Message::from(result))
+    fn send_message_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        sender_id: i32,
+        receiver_id: i32,
+        content: String,
+    ) -> Result<Message> {
+        insert_message(conn, sender_id, receiver_id, content)
     }
 
     async fn get_conversation(&self, brawler_id: i32, friend_id: i32) -> Result<Vec<Message>> {
-        let mut conn = self.pool.get().context("Failed to get DB connection")?;
+        self.pool
+            .run(move |conn| {
+                let results: Vec<MessageDb> = messages::table
+                    .filter(
+                        (messages::sender_id
+                            .eq(brawler_id)
+                            .and(messages::receiver_id.eq(friend_id)))
+                        .or(messages::sender_id
+                            .eq(friend_id)
+                            .and(messages::receiver_id.eq(brawler_id))),
+                    )
+                    .order(messages::created_at.asc())
+                    .load::<MessageDb>(conn)
+                    .context("Error loading conversation")?;
 
-        let results: Vec<MessageDb> = messages::table
-            .filter(
-                (messages::sender_id
-                    .eq(brawler_id)
-                    .and(messages::receiver_id.eq(friend_id)))
-                .or(messages::sender_id
-                    .eq(friend_id)
-                    .and(messages::receiver_id.eq(brawler_id))),
-            )
-            .order(messages::created_at.asc())
-            .load::<MessageDb>(&mut conn)
-            .context("Error loading conversation")?;
-
-        Ok(results.into_iter().map(Message::from).collect())
+                Ok(results.into_iter().map(Message::from).collect())
+            })
+            .await
     }
 
     async fn mark_as_read(&self, receiver_id: i32, sender_id: i32) -> Result<()> {
-        let mut conn = self.pool.get().context("Failed to get DB connection")?;
-
-        diesel::update(messages::table)
-            .filter(messages::receiver_id.eq(receiver_id))
-            .filter(messages::sender_id.eq(sender_id))
-            .filter(messages::read_at.is_null())
-            .set(messages::read_at.eq(diesel::dsl::now))
-            .execute(&mut conn)
-            .context("Error marking messages as read")?;
+        self.pool
+            .run(move |conn| {
+                diesel::update(messages::table)
+                    .filter(messages::receiver_id.eq(receiver_id))
+                    .filter(messages::sender_id.eq(sender_id))
+                    .filter(messages::read_at.is_null())
+                    .set(messages::read_at.eq(diesel::dsl::now))
+                    .execute(conn)
+                    .context("Error marking messages as read")?;
 
-        Ok(())
+                Ok(())
+            })
+            .await
     }
 }
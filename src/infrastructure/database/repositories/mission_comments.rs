@@ -0,0 +1,139 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::mission_comments::{
+    AddMissionCommentEntity, EditMissionCommentEntity, MissionCommentEntity,
+};
+use crate::domain::repositories::mission_comments::MissionCommentRepository;
+use crate::domain::value_object::mission_comment_model::MissionCommentModel;
+use crate::domain::value_object::pagination::Pagination;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::mission_comments;
+
+pub struct MissionCommentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionCommentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionCommentRepository for MissionCommentPostgres {
+    async fn add(&self, add_mission_comment_entity: AddMissionCommentEntity) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::insert_into(mission_comments::table)
+                    .values(&add_mission_comment_entity)
+                    .returning(mission_comments::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn edit(
+        &self,
+        comment_id: i32,
+        brawler_id: i32,
+        edit_mission_comment_entity: EditMissionCommentEntity,
+    ) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::update(mission_comments::table)
+                    .filter(mission_comments::id.eq(comment_id))
+                    .filter(mission_comments::brawler_id.eq(brawler_id))
+                    .filter(mission_comments::deleted_at.is_null())
+                    .set(&edit_mission_comment_entity)
+                    .returning(mission_comments::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn remove(&self, comment_id: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(mission_comments::table)
+                    .filter(mission_comments::id.eq(comment_id))
+                    .filter(mission_comments::deleted_at.is_null())
+                    .set(mission_comments::deleted_at.eq(diesel::dsl::now))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn find_by_id(&self, comment_id: i32) -> Result<MissionCommentEntity> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_comments::table
+                    .filter(mission_comments::id.eq(comment_id))
+                    .filter(mission_comments::deleted_at.is_null())
+                    .select(MissionCommentEntity::as_select())
+                    .first::<MissionCommentEntity>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn gets(
+        &self,
+        mission_id: i32,
+        pagination: &Pagination,
+    ) -> Result<Vec<MissionCommentModel>> {
+        let pagination = pagination.clone();
+
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT
+                        c.id,
+                        c.mission_id,
+                        c.brawler_id,
+                        b.display_name,
+                        b.avatar_url,
+                        c.content,
+                        c.created_at,
+                        c.updated_at
+                    FROM mission_comments c
+                    INNER JOIN brawlers b ON b.id = c.brawler_id
+                    WHERE c.mission_id = $1 AND c.deleted_at IS NULL
+                    ORDER BY c.created_at ASC, c.id ASC
+                    LIMIT $2 OFFSET $3
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .bind::<diesel::sql_types::BigInt, _>(pagination.page_size())
+                    .bind::<diesel::sql_types::BigInt, _>(pagination.offset())
+                    .load::<MissionCommentModel>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn counting(&self, mission_id: i32) -> Result<i64> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_comments::table
+                    .filter(mission_comments::mission_id.eq(mission_id))
+                    .filter(mission_comments::deleted_at.is_null())
+                    .count()
+                    .get_result::<i64>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
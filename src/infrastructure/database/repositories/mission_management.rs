@@ -1,18 +1,43 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use diesel::prelude::*;
+use diesel::{PgConnection, prelude::*};
 use std::sync::Arc;
 
 use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
 use crate::domain::repositories::mission_management::MissionManagementRepository;
 use crate::domain::value_object::mission_statuses::MissionStatuses;
-use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
-use crate::infrastructure::database::schema::missions;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{mission_tags, missions, tags};
 
 pub struct MissionManagementPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
 
+fn insert_mission(conn: &mut PgConnection, add_mission_entity: AddMissionEntity) -> Result<i32> {
+    let result = diesel::insert_into(missions::table)
+        .values(&add_mission_entity)
+        .returning(missions::id)
+        .get_result::<i32>(conn)?;
+
+    Ok(result)
+}
+
+fn update_mission(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    edit_mission_entity: EditMissionEntity,
+) -> Result<i32> {
+    let result = diesel::update(missions::table)
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::deleted_at.is_null())
+        .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+        .set(&edit_mission_entity)
+        .returning(missions::id)
+        .get_result::<i32>(conn)?;
+
+    Ok(result)
+}
+
 impl MissionManagementPostgres {
     pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
         Self { db_pool }
@@ -22,52 +47,94 @@ impl MissionManagementPostgres {
 #[async_trait]
 impl MissionManagementRepository for MissionManagementPostgres {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
-        let mut connection = self
-            .db_pool
-            .get()
-            .map_err(|e| anyhow::Error::msg(e.to_string()))?;
-
-        let result = diesel::insert_into(missions::table)
-            .values(&add_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut connection)?;
+        self.db_pool
+            .run(move |connection| insert_mission(connection, add_mission_entity))
+            .await
+    }
 
-        Ok(result)
+    fn add_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        add_mission_entity: AddMissionEntity,
+    ) -> Result<i32> {
+        insert_mission(conn, add_mission_entity)
     }
 
     async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
-        let mut connection = self
-            .db_pool
-            .get()
-            .map_err(|e| anyhow::Error::msg(e.to_string()))?;
-
-        let result = diesel::update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(&edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut connection)?;
-
-        Ok(result)
+        self.db_pool
+            .run(move |connection| update_mission(connection, mission_id, edit_mission_entity))
+            .await
     }
 
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
-        let mut connection = self
-            .db_pool
-            .get()
-            .map_err(|e| anyhow::Error::msg(e.to_string()))?;
-
-        diesel::update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set((
-                missions::deleted_at.eq(diesel::dsl::now),
-                missions::chief_id.eq(chief_id),
-            ))
-            .execute(&mut connection)?;
+    fn edit_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        edit_mission_entity: EditMissionEntity,
+    ) -> Result<i32> {
+        update_mission(conn, mission_id, edit_mission_entity)
+    }
+
+    fn set_tags_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        tag_names: Vec<String>,
+    ) -> Result<()> {
+        diesel::delete(mission_tags::table)
+            .filter(mission_tags::mission_id.eq(mission_id))
+            .execute(conn)?;
+
+        if tag_names.is_empty() {
+            return Ok(());
+        }
+
+        let new_tags = tag_names
+            .iter()
+            .map(|name| tags::name.eq(name))
+            .collect::<Vec<_>>();
+        diesel::insert_into(tags::table)
+            .values(&new_tags)
+            .on_conflict(tags::name)
+            .do_nothing()
+            .execute(conn)?;
+
+        let tag_ids = tags::table
+            .filter(tags::name.eq_any(&tag_names))
+            .select(tags::id)
+            .load::<i32>(conn)?;
+
+        let links = tag_ids
+            .into_iter()
+            .map(|tag_id| {
+                (
+                    mission_tags::mission_id.eq(mission_id),
+                    mission_tags::tag_id.eq(tag_id),
+                )
+            })
+            .collect::<Vec<_>>();
+        diesel::insert_into(mission_tags::table)
+            .values(&links)
+            .execute(conn)?;
 
         Ok(())
     }
+
+    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .filter(missions::deleted_at.is_null())
+                    .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+                    .set((
+                        missions::deleted_at.eq(diesel::dsl::now),
+                        missions::chief_id.eq(chief_id),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
 }
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::mission_objectives::{
+    AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+};
+use crate::domain::repositories::mission_objectives::MissionObjectiveRepository;
+use crate::domain::value_object::mission_objective_model::MissionObjectiveModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::mission_objectives;
+
+pub struct MissionObjectivePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionObjectivePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionObjectiveRepository for MissionObjectivePostgres {
+    async fn add(&self, add_mission_objective_entity: AddMissionObjectiveEntity) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::insert_into(mission_objectives::table)
+                    .values(&add_mission_objective_entity)
+                    .returning(mission_objectives::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    fn add_many_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        add_mission_objective_entities: Vec<AddMissionObjectiveEntity>,
+    ) -> Result<()> {
+        diesel::insert_into(mission_objectives::table)
+            .values(&add_mission_objective_entities)
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    async fn edit(
+        &self,
+        objective_id: i32,
+        edit_mission_objective_entity: EditMissionObjectiveEntity,
+    ) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::update(mission_objectives::table)
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .set(&edit_mission_objective_entity)
+                    .returning(mission_objectives::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn remove(&self, objective_id: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::delete(mission_objectives::table)
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn find_by_id(&self, objective_id: i32) -> Result<MissionObjectiveEntity> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_objectives::table
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .select(MissionObjectiveEntity::as_select())
+                    .first::<MissionObjectiveEntity>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn gets(&self, mission_id: i32) -> Result<Vec<MissionObjectiveModel>> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT
+                        o.id,
+                        o.mission_id,
+                        o.position,
+                        o.title,
+                        o.is_mandatory,
+                        o.completed_by,
+                        b.display_name AS completed_by_display_name,
+                        o.completed_at
+                    FROM mission_objectives o
+                    LEFT JOIN brawlers b ON b.id = o.completed_by
+                    WHERE o.mission_id = $1
+                    ORDER BY o.position ASC, o.id ASC
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .load::<MissionObjectiveModel>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn next_position(&self, mission_id: i32) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_objectives::table
+                    .filter(mission_objectives::mission_id.eq(mission_id))
+                    .select(diesel::dsl::max(mission_objectives::position))
+                    .first::<Option<i32>>(connection)?;
+
+                Ok(result.map_or(1, |position| position + 1))
+            })
+            .await
+    }
+
+    async fn complete(&self, objective_id: i32, brawler_id: i32) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::update(mission_objectives::table)
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .filter(mission_objectives::completed_at.is_null())
+                    .set((
+                        mission_objectives::completed_by.eq(brawler_id),
+                        mission_objectives::completed_at.eq(diesel::dsl::now),
+                    ))
+                    .returning(mission_objectives::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn uncomplete(&self, objective_id: i32) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::update(mission_objectives::table)
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .set((
+                        mission_objectives::completed_by.eq(None::<i32>),
+                        mission_objectives::completed_at.eq(None::<chrono::NaiveDateTime>),
+                    ))
+                    .returning(mission_objectives::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn count_incomplete_mandatory(&self, mission_id: i32) -> Result<i64> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_objectives::table
+                    .filter(mission_objectives::mission_id.eq(mission_id))
+                    .filter(mission_objectives::is_mandatory.eq(true))
+                    .filter(mission_objectives::completed_at.is_null())
+                    .count()
+                    .get_result::<i64>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
@@ -1,17 +1,48 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use diesel::prelude::*;
+use diesel::{PgConnection, prelude::*};
 use std::sync::Arc;
 
 use crate::domain::repositories::mission_operation::MissionOperationRepository;
 use crate::domain::value_object::mission_statuses::MissionStatuses;
-use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
 use crate::infrastructure::database::schema::missions;
 
 pub struct MissionOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
 
+fn update_status(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    chief_id: i32,
+    expected_from: &[MissionStatuses],
+    status: MissionStatuses,
+) -> Result<i32> {
+    let finished_at = match status {
+        MissionStatuses::Completed | MissionStatuses::Failed => {
+            Some(chrono::Utc::now().naive_utc())
+        }
+        _ => None,
+    };
+
+    diesel::update(missions::table)
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::chief_id.eq(chief_id))
+        .filter(missions::deleted_at.is_null())
+        .filter(missions::status.eq_any(expected_from.iter().map(|s| s.to_string())))
+        .set((
+            missions::status.eq(status.to_string()),
+            missions::finished_at.eq(finished_at),
+        ))
+        .returning(missions::id)
+        .get_result::<i32>(conn)
+        .optional()
+        .context("Failed to execute mission update query")?
+        // The status was checked before the update; another request changed it in between
+        .ok_or_else(|| anyhow::anyhow!("Mission status changed concurrently, reload and try again"))
+}
+
 impl MissionOperationPostgres {
     pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
         Self { db_pool }
@@ -21,45 +52,55 @@ impl MissionOperationPostgres {
         &self,
         mission_id: i32,
         chief_id: i32,
+        expected_from: Vec<MissionStatuses>,
         status: MissionStatuses,
     ) -> Result<i32> {
-        let db_pool = Arc::clone(&self.db_pool);
-        let status_str = status.to_string();
-
-        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
-            let mut conn = db_pool
-                .get()
-                .context("Failed to get database connection from pool")?;
-
-            diesel::update(missions::table)
-                .filter(missions::id.eq(mission_id))
-                .filter(missions::chief_id.eq(chief_id))
-                .filter(missions::deleted_at.is_null())
-                .set((missions::status.eq(status_str),))
-                .returning(missions::id)
-                .get_result::<i32>(&mut conn)
-                .context("Failed to execute mission update query")
-        })
-        .await??;
-
-        Ok(id)
+        self.db_pool
+            .run(move |conn| update_status(conn, mission_id, chief_id, &expected_from, status))
+            .await
     }
 }
 
 #[async_trait]
 impl MissionOperationRepository for MissionOperationPostgres {
     async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        self.set_status(mission_id, chief_id, MissionStatuses::InProgress)
-            .await
+        self.set_status(
+            mission_id,
+            chief_id,
+            vec![MissionStatuses::Open, MissionStatuses::Failed],
+            MissionStatuses::InProgress,
+        )
+        .await
     }
 
     async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        self.set_status(mission_id, chief_id, MissionStatuses::Completed)
-            .await
+        self.set_status(
+            mission_id,
+            chief_id,
+            vec![MissionStatuses::InProgress],
+            MissionStatuses::Completed,
+        )
+        .await
     }
 
     async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        self.set_status(mission_id, chief_id, MissionStatuses::Failed)
-            .await
+        self.set_status(
+            mission_id,
+            chief_id,
+            vec![MissionStatuses::InProgress],
+            MissionStatuses::Failed,
+        )
+        .await
+    }
+
+    fn set_status_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        chief_id: i32,
+        expected_from: MissionStatuses,
+        status: MissionStatuses,
+    ) -> Result<i32> {
+        update_status(conn, mission_id, chief_id, &[expected_from], status)
     }
 }
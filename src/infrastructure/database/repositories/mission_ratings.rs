@@ -0,0 +1,120 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::mission_ratings::{AddMissionRatingEntity, MissionRatingEntity};
+use crate::domain::repositories::mission_ratings::MissionRatingRepository;
+use crate::domain::value_object::mission_rating_model::{MissionRatingModel, ReputationModel};
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::mission_ratings;
+
+pub struct MissionRatingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionRatingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionRatingRepository for MissionRatingPostgres {
+    async fn add(&self, add_mission_rating_entity: AddMissionRatingEntity) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::insert_into(mission_ratings::table)
+                    .values(&add_mission_rating_entity)
+                    .returning(mission_ratings::id)
+                    .get_result::<i32>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn find(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        ratee_id: i32,
+    ) -> Result<Option<MissionRatingEntity>> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_ratings::table
+                    .filter(mission_ratings::mission_id.eq(mission_id))
+                    .filter(mission_ratings::rater_id.eq(rater_id))
+                    .filter(mission_ratings::ratee_id.eq(ratee_id))
+                    .select(MissionRatingEntity::as_select())
+                    .first::<MissionRatingEntity>(connection)
+                    .optional()?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn gets_by_mission(&self, mission_id: i32) -> Result<Vec<MissionRatingModel>> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT
+                        r.id,
+                        r.mission_id,
+                        r.rater_id,
+                        rater.display_name AS rater_display_name,
+                        r.ratee_id,
+                        ratee.display_name AS ratee_display_name,
+                        r.score,
+                        r.comment,
+                        r.created_at
+                    FROM mission_ratings r
+                    INNER JOIN brawlers rater ON rater.id = r.rater_id
+                    INNER JOIN brawlers ratee ON ratee.id = r.ratee_id
+                    WHERE r.mission_id = $1
+                    ORDER BY r.created_at ASC
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .load::<MissionRatingModel>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT
+                        b.id AS brawler_id,
+                        r.rating_average,
+                        COALESCE(r.rating_count, 0) AS rating_count,
+                        COALESCE(d.abandoned_count, 0) AS abandoned_count
+                    FROM brawlers b
+                    LEFT JOIN (
+                        SELECT ratee_id, AVG(score)::FLOAT8 AS rating_average, COUNT(*) AS rating_count
+                        FROM mission_ratings
+                        GROUP BY ratee_id
+                    ) r ON r.ratee_id = b.id
+                    LEFT JOIN (
+                        SELECT brawler_id, COUNT(*) AS abandoned_count
+                        FROM crew_departures
+                        WHERE abandoned
+                        GROUP BY brawler_id
+                    ) d ON d.brawler_id = b.id
+                    WHERE b.id = $1
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .get_result::<ReputationModel>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
@@ -0,0 +1,187 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeDelta};
+use diesel::{
+    PgConnection,
+    prelude::*,
+    sql_types::{BigInt, Int4},
+};
+use std::sync::Arc;
+
+use crate::domain::entities::mission_series::{AddMissionSeriesEntity, MissionSeriesEntity};
+use crate::domain::repositories::mission_series::MissionSeriesRepository;
+use crate::domain::value_object::recurrence::SeriesOccurrenceModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{mission_series, missions};
+
+const DUE_CONDITION: &str = r#"
+    s.is_active AND (
+        s.next_occurrence_at <= NOW() OR NOT EXISTS (
+            SELECT 1 FROM missions m
+            WHERE m.series_id = s.id
+                AND m.deleted_at IS NULL
+                AND m.status IN ('Open', 'InProgress')
+        )
+    )
+"#;
+
+#[derive(QueryableByName)]
+struct SeriesId {
+    #[diesel(sql_type = Int4)]
+    id: i32,
+}
+
+pub struct MissionSeriesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionSeriesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionSeriesRepository for MissionSeriesPostgres {
+    fn add_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        add_mission_series_entity: AddMissionSeriesEntity,
+    ) -> Result<i32> {
+        let result = diesel::insert_into(mission_series::table)
+            .values(&add_mission_series_entity)
+            .returning(mission_series::id)
+            .get_result::<i32>(conn)?;
+
+        Ok(result)
+    }
+
+    async fn find(&self, series_id: i32) -> Result<MissionSeriesEntity> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_series::table
+                    .filter(mission_series::id.eq(series_id))
+                    .select(MissionSeriesEntity::as_select())
+                    .first::<MissionSeriesEntity>(connection)
+                    .optional()?
+                    .ok_or_else(|| anyhow::anyhow!("Series not found"))?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn stop(&self, series_id: i32, chief_id: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                let updated = diesel::update(mission_series::table)
+                    .filter(mission_series::id.eq(series_id))
+                    .filter(mission_series::chief_id.eq(chief_id))
+                    .set(mission_series::is_active.eq(false))
+                    .execute(connection)?;
+
+                if updated == 0 {
+                    return Err(anyhow::anyhow!("Series not found or you are not its chief"));
+                }
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn due_ids(&self, limit: i64) -> Result<Vec<i32>> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = format!(
+                    "SELECT s.id FROM mission_series s WHERE {} ORDER BY s.next_occurrence_at LIMIT $1",
+                    DUE_CONDITION
+                );
+                let result = diesel::sql_query(sql)
+                    .bind::<BigInt, _>(limit)
+                    .load::<SeriesId>(connection)?
+                    .into_iter()
+                    .map(|row| row.id)
+                    .collect();
+
+                Ok(result)
+            })
+            .await
+    }
+
+    fn lock_due_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        series_id: i32,
+    ) -> Result<Option<MissionSeriesEntity>> {
+        let sql = format!(
+            "SELECT s.* FROM mission_series s WHERE s.id = $1 AND {} FOR UPDATE SKIP LOCKED",
+            DUE_CONDITION
+        );
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(series_id)
+            .get_result::<MissionSeriesEntity>(conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    fn latest_mission_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        series_id: i32,
+    ) -> Result<Option<SeriesOccurrenceModel>> {
+        let result = missions::table
+            .filter(missions::series_id.eq(series_id))
+            .filter(missions::deleted_at.is_null())
+            .order((
+                missions::scheduled_for.desc().nulls_last(),
+                missions::id.desc(),
+            ))
+            .select((
+                missions::id,
+                missions::scheduled_for,
+                missions::created_at,
+                missions::deadline,
+            ))
+            .first::<(
+                i32,
+                Option<NaiveDateTime>,
+                NaiveDateTime,
+                Option<NaiveDateTime>,
+            )>(conn)
+            .optional()?;
+
+        // The first mission of a series was created by hand and has no scheduled_for
+        Ok(result.map(
+            |(mission_id, scheduled_for, created_at, deadline)| SeriesOccurrenceModel {
+                mission_id,
+                deadline_offset: deadline
+                    .map(|deadline| deadline - scheduled_for.unwrap_or(created_at))
+                    .filter(|offset| *offset > TimeDelta::zero()),
+            },
+        ))
+    }
+
+    fn advance_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        series_id: i32,
+        next_occurrence_at: NaiveDateTime,
+    ) -> Result<()> {
+        diesel::update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .set(mission_series::next_occurrence_at.eq(next_occurrence_at))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    fn deactivate_in_transaction(&self, conn: &mut PgConnection, series_id: i32) -> Result<()> {
+        diesel::update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .set(mission_series::is_active.eq(false))
+            .execute(conn)?;
+
+        Ok(())
+    }
+}
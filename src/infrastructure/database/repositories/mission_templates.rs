@@ -0,0 +1,175 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::mission_templates::{AddMissionTemplateEntity, MissionTemplateEntity};
+use crate::domain::entities::missions::MissionEntity;
+use crate::domain::repositories::mission_templates::MissionTemplateRepository;
+use crate::domain::value_object::mission_template_model::{
+    MissionBlueprint, TemplateObjectiveModel,
+};
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{
+    brawlers, mission_objectives, mission_tags, mission_templates, missions, tags,
+};
+
+pub struct MissionTemplatePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+fn load_blueprint(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    chief_id: i32,
+) -> Result<MissionBlueprint> {
+    let mission = missions::table
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::chief_id.eq(chief_id))
+        .filter(missions::deleted_at.is_null())
+        .select(MissionEntity::as_select())
+        .first::<MissionEntity>(conn)
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("Mission not found or you are not its chief"))?;
+
+    let tag_names = mission_tags::table
+        .inner_join(tags::table)
+        .filter(mission_tags::mission_id.eq(mission_id))
+        .order(tags::name.asc())
+        .select(tags::name)
+        .load::<String>(conn)?;
+
+    let objectives = mission_objectives::table
+        .filter(mission_objectives::mission_id.eq(mission_id))
+        .order(mission_objectives::position.asc())
+        .select((mission_objectives::title, mission_objectives::is_mandatory))
+        .load::<(String, bool)>(conn)?
+        .into_iter()
+        .map(|(title, is_mandatory)| TemplateObjectiveModel {
+            title,
+            is_mandatory,
+        })
+        .collect();
+
+    Ok(MissionBlueprint {
+        name: mission.name,
+        description: mission.description,
+        comment_visibility: mission.comment_visibility,
+        require_objectives: mission.require_objectives,
+        category: mission.category,
+        tags: tag_names,
+        objectives,
+    })
+}
+
+impl MissionTemplatePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionTemplateRepository for MissionTemplatePostgres {
+    async fn add(
+        &self,
+        add_mission_template_entity: AddMissionTemplateEntity,
+        max_per_owner: usize,
+    ) -> Result<i32> {
+        self.db_pool
+            .run(move |connection| {
+                connection.transaction(|connection| {
+                    let owner_id = add_mission_template_entity.owner_id;
+                    brawlers::table
+                        .filter(brawlers::id.eq(owner_id))
+                        .select(brawlers::id)
+                        .for_update()
+                        .first::<i32>(connection)?;
+
+                    let existing = mission_templates::table
+                        .filter(mission_templates::owner_id.eq(owner_id))
+                        .count()
+                        .get_result::<i64>(connection)?;
+                    if existing >= i64::try_from(max_per_owner)? {
+                        return Err(anyhow::anyhow!(
+                            "A brawler can keep at most {} templates",
+                            max_per_owner
+                        ));
+                    }
+
+                    let result = diesel::insert_into(mission_templates::table)
+                        .values(&add_mission_template_entity)
+                        .returning(mission_templates::id)
+                        .get_result::<i32>(connection)?;
+
+                    Ok(result)
+                })
+            })
+            .await
+    }
+
+    async fn find(&self, template_id: i32, owner_id: i32) -> Result<MissionTemplateEntity> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_templates::table
+                    .filter(mission_templates::id.eq(template_id))
+                    .filter(mission_templates::owner_id.eq(owner_id))
+                    .select(MissionTemplateEntity::as_select())
+                    .first::<MissionTemplateEntity>(connection)
+                    .optional()?
+                    .ok_or_else(|| anyhow::anyhow!("Template not found"))?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn list(&self, owner_id: i32) -> Result<Vec<MissionTemplateEntity>> {
+        self.db_pool
+            .run(move |connection| {
+                let result = mission_templates::table
+                    .filter(mission_templates::owner_id.eq(owner_id))
+                    .order(mission_templates::created_at.desc())
+                    .select(MissionTemplateEntity::as_select())
+                    .load::<MissionTemplateEntity>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                let deleted = diesel::delete(mission_templates::table)
+                    .filter(mission_templates::id.eq(template_id))
+                    .filter(mission_templates::owner_id.eq(owner_id))
+                    .execute(connection)?;
+
+                if deleted == 0 {
+                    return Err(anyhow::anyhow!("Template not found"));
+                }
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn blueprint_of_mission(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> Result<MissionBlueprint> {
+        self.db_pool
+            .run(move |connection| load_blueprint(connection, mission_id, chief_id))
+            .await
+    }
+
+    fn blueprint_of_mission_in_transaction(
+        &self,
+        conn: &mut PgConnection,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> Result<MissionBlueprint> {
+        load_blueprint(conn, mission_id, chief_id)
+    }
+}
@@ -2,18 +2,158 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::{
+    ExpressionMethods, QueryDsl, QueryableByName, RunQueryDsl,
+    pg::Pg,
+    query_builder::{BoxedSqlQuery, SqlQuery},
+};
 
 use crate::{
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
         value_object::{
-            brawler_model::BrawlerModel, mission_filter::MissionFilter,
-            mission_moddel::MissionModel,
+            brawler_model::BrawlerModel,
+            mission_filter::MissionFilter,
+            mission_moddel::{MissionModel, MissionSearchResultModel},
+            mission_sort::MissionSort,
+            pagination::Pagination,
+            tag_model::TagMatch,
         },
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
+    infrastructure::database::{
+        postgresql_connection::{PgPoolRunner, PgPoolSquad},
+        schema::crew_memberships,
+    },
 };
+
+// Only these fixed fragments ever reach the ORDER BY clause, so the sort option cannot inject SQL.
+// $7 is the crew limit bound in `bind_search`
+fn order_by(sort: &MissionSort) -> &'static str {
+    match sort {
+        MissionSort::Relevance => "search_rank DESC, m.created_at DESC",
+        MissionSort::Newest => "m.created_at DESC",
+        MissionSort::MostCrew => "crew_count DESC, m.created_at DESC",
+        MissionSort::FewestFreeSlots => {
+            "(COALESCE(cc.crew_count, 0) >= $7) ASC, crew_count DESC, m.created_at DESC"
+        }
+        MissionSort::Deadline => "m.deadline ASC NULLS LAST, m.created_at DESC",
+    }
+}
+
+// Shared by `gets` and `counting`, which add the select list and paging around it
+const SEARCH_FROM_WHERE: &str = r#"
+    FROM missions m
+    CROSS JOIN (SELECT to_tsquery('english', $4) AS query) sq
+    INNER JOIN brawlers b ON b.id = m.chief_id
+    LEFT JOIN (
+        SELECT mission_id, COUNT(*) as crew_count 
+        FROM crew_memberships 
+        GROUP BY mission_id
+    ) cc ON cc.mission_id = m.id
+    LEFT JOIN (
+        SELECT mission_id, COUNT(*) as objective_count, COUNT(completed_at) as completed_count
+        FROM mission_objectives
+        GROUP BY mission_id
+    ) oc ON oc.mission_id = m.id
+    WHERE 
+        m.deleted_at IS NULL AND
+        ($1 IS NULL OR m.status = ANY($1)) AND
+        ($2 IS NULL OR m.name ILIKE $2) AND
+        (sq.query IS NULL OR m.search_vector @@ sq.query) AND
+        ($3 IS NULL OR EXISTS (
+            SELECT 1 FROM crew_memberships cm 
+            WHERE cm.mission_id = m.id AND cm.brawler_id = $3
+        )) AND
+        ($5 IS NULL OR m.chief_id = $5) AND
+        ($6 IS NULL OR (COALESCE(cc.crew_count, 0) < $7) = $6) AND
+        ($8 IS NULL OR (m.chief_id <> $8 AND NOT EXISTS (
+            SELECT 1 FROM crew_memberships cm 
+            WHERE cm.mission_id = m.id AND cm.brawler_id = $8
+        ))) AND
+        ($9 IS NULL OR m.created_at >= $9) AND
+        ($10 IS NULL OR m.created_at < $10 + 1) AND
+        ($11 IS NULL OR (
+            SELECT COUNT(*) FROM mission_tags mt
+            INNER JOIN tags t ON t.id = mt.tag_id
+            WHERE mt.mission_id = m.id AND t.name = ANY($11)
+        ) >= CASE WHEN $12 THEN cardinality($11) ELSE 1 END) AND
+        ($13 IS NULL OR m.category = $13) AND
+        ($14 IS NULL OR m.series_id = $14)
+"#;
+
+// ts_headline marks matches with these control characters, then the text is HTML-escaped and
+// only they become <mark> tags; they are stripped from the stored text first
+const HIGHLIGHT_START: char = '\u{1}';
+const HIGHLIGHT_STOP: char = '\u{2}';
+
+fn highlight_html(raw: &str) -> String {
+    let mut html = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            HIGHLIGHT_START => html.push_str("<mark>"),
+            HIGHLIGHT_STOP => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+// Binds $1 to $14 of SEARCH_FROM_WHERE
+fn bind_search<'a>(
+    query: BoxedSqlQuery<'a, Pg, SqlQuery>,
+    mission_filter: &MissionFilter,
+    viewer_id: Option<i32>,
+    max_crew_per_mission: u32,
+) -> Result<BoxedSqlQuery<'a, Pg, SqlQuery>> {
+    use diesel::sql_types::{Array, BigInt, Bool, Date, Int4, Nullable, Text, Varchar};
+
+    let statuses = mission_filter
+        .status_list()?
+        .iter()
+        .map(|status| status.to_string())
+        .collect::<Vec<_>>();
+    let statuses_bind = if statuses.is_empty() {
+        None
+    } else {
+        Some(statuses)
+    };
+    let name_bind: Option<String> = mission_filter.name.as_ref().map(|n| format!("%{}%", n));
+    let brawler_id_bind = mission_filter.brawler_id;
+    let search_bind = mission_filter.search_query();
+    let not_joined_bind = viewer_id.filter(|_| mission_filter.not_joined == Some(true));
+    let tags = mission_filter.tag_list()?;
+    let tags_bind = if tags.is_empty() { None } else { Some(tags) };
+    let match_all_tags = mission_filter.tag_match == Some(TagMatch::All);
+    let category_bind = mission_filter.category.as_ref().map(|c| c.to_string());
+
+    Ok(query
+        .bind::<Nullable<Array<Text>>, _>(statuses_bind)
+        .bind::<Nullable<Varchar>, _>(name_bind)
+        .bind::<Nullable<Int4>, _>(brawler_id_bind)
+        .bind::<Nullable<Text>, _>(search_bind)
+        .bind::<Nullable<Int4>, _>(mission_filter.chief_id)
+        .bind::<Nullable<Bool>, _>(mission_filter.has_free_slots)
+        .bind::<BigInt, _>(i64::from(max_crew_per_mission))
+        .bind::<Nullable<Int4>, _>(not_joined_bind)
+        .bind::<Nullable<Date>, _>(mission_filter.created_from)
+        .bind::<Nullable<Date>, _>(mission_filter.created_to)
+        .bind::<Nullable<Array<Text>>, _>(tags_bind)
+        .bind::<Bool, _>(match_all_tags)
+        .bind::<Nullable<Varchar>, _>(category_bind)
+        .bind::<Nullable<Int4>, _>(mission_filter.series_id))
+}
+
+#[derive(QueryableByName)]
+struct SearchTotal {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    total: i64,
+}
+
 pub struct MissionViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -27,138 +167,280 @@ impl MissionViewingPostgres {
 #[async_trait]
 impl MissionViewingRepository for MissionViewingPostgres {
     async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+        self.db_pool
+            .run(move |conn| {
+                let value = crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .count()
+                    .first::<i64>(conn)?;
+
+                let count = u32::try_from(value)?;
+                Ok(count)
+            })
+            .await
+    }
 
-        let value = crew_memberships::table
-            .filter(crew_memberships::mission_id.eq(mission_id))
-            .count()
-            .first::<i64>(&mut conn)?;
+    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+        self.db_pool
+            .run(move |conn| {
+                let value = crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(brawler_id))
+                    .count()
+                    .first::<i64>(conn)?;
 
-        let count = u32::try_from(value)?;
-        Ok(count)
+                Ok(value > 0)
+            })
+            .await
     }
 
     async fn view_detail(&self, mission_id: i32) -> Result<MissionModel> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        let sql = r#"
-            SELECT 
-                m.id, 
-                m.name, 
-                m.description, 
-                m.status, 
-                m.chief_id, 
-                b.display_name as chief_display_name,
-                COALESCE(cc.crew_count, 0) as crew_count,
-                m.created_at, 
-                m.updated_at
-            FROM missions m
-            INNER JOIN brawlers b ON b.id = m.chief_id
-            LEFT JOIN (
-                SELECT mission_id, COUNT(*) as crew_count 
-                FROM crew_memberships 
-                GROUP BY mission_id
-            ) cc ON cc.mission_id = m.id
-            WHERE m.id = $1 AND m.deleted_at IS NULL
-        "#;
-
-        let result = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Int4, _>(mission_id)
-            .get_result::<MissionModel>(&mut conn)?;
-
-        Ok(result)
+        self.db_pool
+            .run(move |conn| {
+                let sql = r#"
+                    SELECT 
+                        m.id, 
+                        m.name, 
+                        m.description, 
+                        m.status, 
+                        m.chief_id, 
+                        b.display_name as chief_display_name,
+                        COALESCE(cc.crew_count, 0) as crew_count,
+                        m.comment_visibility,
+                        m.require_objectives,
+                        COALESCE(
+                            100.0 * oc.completed_count / NULLIF(oc.objective_count, 0),
+                            0
+                        )::FLOAT8 as completion_percentage,
+                        m.created_at, 
+                        m.updated_at,
+                        m.finished_at,
+                        m.deadline,
+                        m.category,
+                        ARRAY(
+                            SELECT t.name FROM mission_tags mt
+                            INNER JOIN tags t ON t.id = mt.tag_id
+                            WHERE mt.mission_id = m.id
+                            ORDER BY t.name
+                        )::TEXT[] as tags,
+                        m.series_id,
+                        m.scheduled_for
+                    FROM missions m
+                    INNER JOIN brawlers b ON b.id = m.chief_id
+                    LEFT JOIN (
+                        SELECT mission_id, COUNT(*) as crew_count 
+                        FROM crew_memberships 
+                        GROUP BY mission_id
+                    ) cc ON cc.mission_id = m.id
+                    LEFT JOIN (
+                        SELECT mission_id, COUNT(*) as objective_count, COUNT(completed_at) as completed_count
+                        FROM mission_objectives
+                        GROUP BY mission_id
+                    ) oc ON oc.mission_id = m.id
+                    WHERE m.id = $1 AND m.deleted_at IS NULL
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .get_result::<MissionModel>(conn)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn gets(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        max_crew_per_mission: u32,
+        pagination: &Pagination,
+    ) -> Result<Vec<MissionSearchResultModel>> {
+        use diesel::sql_types::BigInt;
+
+        let mission_filter = mission_filter.clone();
+        let pagination = pagination.clone();
+
+        self.db_pool
+            .run(move |conn| {
+                let sql = format!(
+                    r#"
+                    SELECT 
+                        m.id, 
+                        m.name, 
+                        m.description, 
+                        m.status, 
+                        m.chief_id, 
+                        b.display_name as chief_display_name,
+                        COALESCE(cc.crew_count, 0) as crew_count,
+                        m.comment_visibility,
+                        m.require_objectives,
+                        COALESCE(
+                            100.0 * oc.completed_count / NULLIF(oc.objective_count, 0),
+                            0
+                        )::FLOAT8 as completion_percentage,
+                        m.created_at, 
+                        m.updated_at,
+                        m.finished_at,
+                        m.deadline,
+                        m.category,
+                        ARRAY(
+                            SELECT t.name FROM mission_tags mt
+                            INNER JOIN tags t ON t.id = mt.tag_id
+                            WHERE mt.mission_id = m.id
+                            ORDER BY t.name
+                        )::TEXT[] as tags,
+                        m.series_id,
+                        m.scheduled_for,
+                        COALESCE(ts_rank_cd(m.search_vector, sq.query), 0)::FLOAT8 AS search_rank,
+                        CASE WHEN sq.query IS NOT NULL THEN ts_headline(
+                            'english', translate(m.name, chr(1) || chr(2), ''), sq.query,
+                            'StartSel=' || chr(1) || ', StopSel=' || chr(2) || ', HighlightAll=true'
+                        ) END AS name_highlight,
+                        CASE WHEN sq.query IS NOT NULL AND m.description IS NOT NULL THEN ts_headline(
+                            'english', translate(m.description, chr(1) || chr(2), ''), sq.query,
+                            'StartSel=' || chr(1) || ', StopSel=' || chr(2) || ', MaxWords=30, MinWords=10, MaxFragments=2'
+                        ) END AS description_snippet
+                    {}
+                    ORDER BY {}
+                    LIMIT $15 OFFSET $16
+                "#,
+                    SEARCH_FROM_WHERE,
+                    order_by(&mission_filter.effective_sort())
+                );
+
+                let query = diesel::sql_query(sql).into_boxed::<Pg>();
+                let rows = bind_search(query, &mission_filter, viewer_id, max_crew_per_mission)?
+                    .bind::<BigInt, _>(pagination.page_size())
+                    .bind::<BigInt, _>(pagination.offset())
+                    .load::<MissionSearchResultModel>(conn)?
+                    .into_iter()
+                    .map(|mut row| {
+                        row.name_highlight = row.name_highlight.as_deref().map(highlight_html);
+                        row.description_snippet =
+                            row.description_snippet.as_deref().map(highlight_html);
+                        row
+                    })
+                    .collect();
+
+                Ok(rows)
+            })
+            .await
     }
 
-    async fn gets(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        use diesel::sql_types::{Nullable, Varchar};
-
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        let sql = r#"
-            SELECT 
-                m.id, 
-                m.name, 
-                m.description, 
-                m.status, 
-                m.chief_id, 
-                b.display_name as chief_display_name,
-                COALESCE(cc.crew_count, 0) as crew_count,
-                m.created_at, 
-                m.updated_at
-            FROM missions m
-            INNER JOIN brawlers b ON b.id = m.chief_id
-            LEFT JOIN (
-                SELECT mission_id, COUNT(*) as crew_count 
-                FROM crew_memberships 
-                GROUP BY mission_id
-            ) cc ON cc.mission_id = m.id
-            WHERE 
-                m.deleted_at IS NULL AND
-                ($1 IS NULL OR m.status = $1) AND
-                ($2 IS NULL OR m.name ILIKE $2) AND
-                ($3 IS NULL OR EXISTS (
-                    SELECT 1 FROM crew_memberships cm 
-                    WHERE cm.mission_id = m.id AND cm.brawler_id = $3
-                ))
-            ORDER BY m.created_at DESC
-        "#;
-
-        let status_bind: Option<String> = mission_filter.status.as_ref().map(|s| s.to_string());
-        let name_bind: Option<String> = mission_filter.name.as_ref().map(|n| format!("%{}%", n));
-        let brawler_id_bind = mission_filter.brawler_id;
-
-        let rows = diesel::sql_query(sql)
-            .bind::<Nullable<Varchar>, _>(status_bind)
-            .bind::<Nullable<Varchar>, _>(name_bind)
-            .bind::<Nullable<diesel::sql_types::Int4>, _>(brawler_id_bind)
-            .load::<MissionModel>(&mut conn)?;
-
-        Ok(rows)
+    async fn counting(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        max_crew_per_mission: u32,
+    ) -> Result<i64> {
+        let mission_filter = mission_filter.clone();
+
+        self.db_pool
+            .run(move |conn| {
+                let sql = format!("SELECT COUNT(*) AS total {}", SEARCH_FROM_WHERE);
+
+                let query = diesel::sql_query(sql).into_boxed::<Pg>();
+                let row = bind_search(query, &mission_filter, viewer_id, max_crew_per_mission)?
+                    .get_result::<SearchTotal>(conn)?;
+
+                Ok(row.total)
+            })
+            .await
     }
+
     async fn get_mission_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        let sql = r#"
-            SELECT 
-               b.display_name AS display_name,
-               COALESCE(b.avatar_url, '') AS avatar_url,
-               COALESCE(s.success_count, 0) AS mission_success_count,
-               COALESCE(j.joined_count, 0) AS mission_joined_count
-            FROM 
-                crew_memberships cm
-            INNER JOIN 
-                brawlers b ON b.id = cm.brawler_id 
-            LEFT JOIN 
-                (
+        self.db_pool
+            .run(move |conn| {
+                let sql = r#"
                     SELECT 
-                        cm2.brawler_id, 
-                        COUNT(*) AS success_count
+                       b.id AS id,
+                       b.display_name AS display_name,
+                       COALESCE(b.avatar_url, '') AS avatar_url,
+                       COALESCE(s.success_count, 0) AS mission_success_count,
+                       COALESCE(j.joined_count, 0) AS mission_joined_count,
+                       r.rating_average AS rating_average,
+                       COALESCE(r.rating_count, 0) AS rating_count,
+                       COALESCE(d.abandoned_count, 0) AS abandoned_count,
+                       COALESCE(x.xp, 0) AS xp,
+                       brawler_level(COALESCE(x.xp, 0)) AS level,
+                       ARRAY(
+                           SELECT ba.achievement::TEXT
+                           FROM brawler_achievements ba
+                           WHERE ba.brawler_id = b.id
+                           ORDER BY ba.unlocked_at
+                       ) AS achievements
                     FROM 
-                        crew_memberships cm2
+                        crew_memberships cm
                     INNER JOIN 
-                        missions m2 ON m2.id = cm2.mission_id
+                        brawlers b ON b.id = cm.brawler_id 
+                    LEFT JOIN 
+                        (
+                            SELECT 
+                                cm2.brawler_id, 
+                                COUNT(*) AS success_count
+                            FROM 
+                                crew_memberships cm2
+                            INNER JOIN 
+                                missions m2 ON m2.id = cm2.mission_id
+                            WHERE 
+                                m2.status = 'Completed'
+                            GROUP BY 
+                                cm2.brawler_id
+                        ) s ON s.brawler_id = cm.brawler_id
+                    LEFT JOIN 
+                        (
+                            SELECT 
+                                cm3.brawler_id, 
+                                COUNT(*) AS joined_count
+                            FROM 
+                                crew_memberships cm3
+                            GROUP BY 
+                                cm3.brawler_id
+                        ) j ON j.brawler_id = b.id
+                    LEFT JOIN 
+                        (
+                            SELECT 
+                                ratee_id, 
+                                AVG(score)::FLOAT8 AS rating_average,
+                                COUNT(*) AS rating_count
+                            FROM 
+                                mission_ratings
+                            GROUP BY 
+                                ratee_id
+                        ) r ON r.ratee_id = b.id
+                    LEFT JOIN 
+                        (
+                            SELECT 
+                                brawler_id, 
+                                COUNT(*) AS abandoned_count
+                            FROM 
+                                crew_departures
+                            WHERE 
+                                abandoned
+                            GROUP BY 
+                                brawler_id
+                        ) d ON d.brawler_id = b.id
+                    LEFT JOIN 
+                        (
+                            SELECT 
+                                brawler_id, 
+                                SUM(amount)::BIGINT AS xp
+                            FROM 
+                                xp_awards
+                            GROUP BY 
+                                brawler_id
+                        ) x ON x.brawler_id = b.id
                     WHERE 
-                        m2.status = 'completed'
-                    GROUP BY 
-                        cm2.brawler_id
-                ) s ON s.brawler_id = cm.brawler_id
-            LEFT JOIN 
-                (
-                    SELECT 
-                        cm3.brawler_id, 
-                        COUNT(*) AS joined_count
-                    FROM 
-                        crew_memberships cm3
-                    GROUP BY 
-                        cm3.brawler_id
-                ) j ON j.brawler_id = b.id
-            WHERE 
-                cm.mission_id = $1
-        "#;
-
-        let result = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Int4, _>(mission_id)
-            .load::<BrawlerModel>(&mut conn)?;
-
-        Ok(result)
+                        cm.mission_id = $1
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .load::<BrawlerModel>(conn)?;
+
+                Ok(result)
+            })
+            .await
     }
 }
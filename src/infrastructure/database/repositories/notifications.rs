@@ -0,0 +1,136 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::notifications::{AddNotificationEntity, NotificationEntity};
+use crate::domain::repositories::notifications::NotificationRepository;
+use crate::domain::value_object::notification_model::NotificationModel;
+use crate::domain::value_object::pagination::Pagination;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::notifications;
+use crate::infrastructure::realtime::notification_hub::NotificationHub;
+
+pub struct NotificationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+    notification_hub: Arc<NotificationHub>,
+}
+
+impl NotificationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>, notification_hub: Arc<NotificationHub>) -> Self {
+        Self {
+            db_pool,
+            notification_hub,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for NotificationPostgres {
+    async fn add(
+        &self,
+        add_notification_entities: Vec<AddNotificationEntity>,
+    ) -> Result<Vec<NotificationEntity>> {
+        if add_notification_entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let results = self
+            .db_pool
+            .run(move |connection| {
+                let results = diesel::insert_into(notifications::table)
+                    .values(&add_notification_entities)
+                    .returning(NotificationEntity::as_returning())
+                    .get_results::<NotificationEntity>(connection)?;
+
+                Ok(results)
+            })
+            .await?;
+
+        // Push only after the rows are stored so a live client never sees an id it cannot list
+        for notification in results.iter().cloned() {
+            self.notification_hub
+                .publish(NotificationModel::from(notification));
+        }
+
+        Ok(results)
+    }
+
+    async fn gets(
+        &self,
+        recipient_id: i32,
+        pagination: &Pagination,
+    ) -> Result<Vec<NotificationEntity>> {
+        let pagination = pagination.clone();
+
+        self.db_pool
+            .run(move |connection| {
+                let results = notifications::table
+                    .filter(notifications::recipient_id.eq(recipient_id))
+                    .order((notifications::created_at.desc(), notifications::id.desc()))
+                    .limit(pagination.page_size())
+                    .offset(pagination.offset())
+                    .select(NotificationEntity::as_select())
+                    .load::<NotificationEntity>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn counting(&self, recipient_id: i32) -> Result<i64> {
+        self.db_pool
+            .run(move |connection| {
+                let result = notifications::table
+                    .filter(notifications::recipient_id.eq(recipient_id))
+                    .count()
+                    .get_result::<i64>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn unread_counting(&self, recipient_id: i32) -> Result<i64> {
+        self.db_pool
+            .run(move |connection| {
+                let result = notifications::table
+                    .filter(notifications::recipient_id.eq(recipient_id))
+                    .filter(notifications::read_at.is_null())
+                    .count()
+                    .get_result::<i64>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn mark_read(&self, notification_id: i32, recipient_id: i32) -> Result<usize> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::update(notifications::table)
+                    .filter(notifications::id.eq(notification_id))
+                    .filter(notifications::recipient_id.eq(recipient_id))
+                    .filter(notifications::read_at.is_null())
+                    .set(notifications::read_at.eq(diesel::dsl::now))
+                    .execute(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn mark_all_read(&self, recipient_id: i32) -> Result<usize> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::update(notifications::table)
+                    .filter(notifications::recipient_id.eq(recipient_id))
+                    .filter(notifications::read_at.is_null())
+                    .set(notifications::read_at.eq(diesel::dsl::now))
+                    .execute(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
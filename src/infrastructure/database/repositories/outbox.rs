@@ -0,0 +1,187 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{PgConnection, prelude::*};
+use std::sync::Arc;
+
+use crate::domain::entities::outbox::{AddOutboxEntity, OutboxEntity};
+use crate::domain::events::DomainEvent;
+use crate::domain::repositories::outbox::OutboxRepository;
+use crate::domain::repositories::transaction_provider::TransactionProvider;
+use crate::domain::value_object::outbox_model::OutboxLagModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::outbox;
+
+pub struct OutboxPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl OutboxPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxPostgres {
+    fn stage(&self, conn: &mut PgConnection, events: Vec<DomainEvent>) -> Result<()> {
+        let rows = events
+            .iter()
+            .map(AddOutboxEntity::from_event)
+            .collect::<Result<Vec<_>>>()?;
+
+        diesel::insert_into(outbox::table)
+            .values(&rows)
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_seconds: i64,
+        max_attempts: i32,
+    ) -> Result<Vec<OutboxEntity>> {
+        self.db_pool
+            .run(move |connection| {
+                // Same lease trick as webhook deliveries: a relay that dies mid-batch leaves the rows
+                // to be picked up again once next_attempt_at passes
+                let sql = r#"
+                    UPDATE outbox
+                    SET next_attempt_at = NOW() + make_interval(secs => $2::FLOAT8),
+                        attempts = attempts + 1
+                    WHERE id IN (
+                        SELECT id
+                        FROM outbox
+                        WHERE processed_at IS NULL AND next_attempt_at <= NOW() AND attempts < $3
+                        ORDER BY id ASC
+                        LIMIT $1
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    RETURNING id, event_type, payload, handled_by, attempts, next_attempt_at,
+                              last_error, created_at, processed_at
+                "#;
+
+                let mut results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::BigInt, _>(limit)
+                    .bind::<diesel::sql_types::BigInt, _>(lease_seconds)
+                    .bind::<diesel::sql_types::Integer, _>(max_attempts)
+                    .load::<OutboxEntity>(connection)?;
+                results.sort_by_key(|entry| entry.id);
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn mark_handled(&self, outbox_id: i64, subscriber: String) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::sql_query(
+                    "UPDATE outbox SET handled_by = array_append(handled_by, $2) \
+                     WHERE id = $1 AND NOT ($2 = ANY(handled_by))",
+                )
+                .bind::<diesel::sql_types::BigInt, _>(outbox_id)
+                .bind::<diesel::sql_types::Text, _>(subscriber)
+                .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn mark_processed(&self, outbox_id: i64) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(outbox::table)
+                    .filter(outbox::id.eq(outbox_id))
+                    .set((
+                        outbox::processed_at.eq(diesel::dsl::now),
+                        outbox::last_error.eq(None::<String>),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn mark_retry(
+        &self,
+        outbox_id: i64,
+        error: String,
+        next_attempt_at: NaiveDateTime,
+    ) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(outbox::table)
+                    .filter(outbox::id.eq(outbox_id))
+                    .set((
+                        outbox::last_error.eq(Some(error)),
+                        outbox::next_attempt_at.eq(next_attempt_at),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn get_lag(&self, max_attempts: i32) -> Result<OutboxLagModel> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT
+                        COUNT(*) FILTER (WHERE processed_at IS NULL AND attempts < $1) AS pending_count,
+                        COUNT(*) FILTER (
+                            WHERE processed_at IS NULL AND last_error IS NOT NULL AND attempts < $1
+                        ) AS retrying_count,
+                        COUNT(*) FILTER (WHERE processed_at IS NULL AND attempts >= $1) AS dead_count,
+                        COALESCE(
+                            EXTRACT(EPOCH FROM NOW() - MIN(created_at) FILTER (
+                                WHERE processed_at IS NULL AND attempts < $1
+                            )),
+                            0
+                        )::FLOAT8 AS oldest_pending_seconds,
+                        COUNT(*) FILTER (
+                            WHERE processed_at >= NOW() - INTERVAL '1 hour'
+                        ) AS processed_last_hour,
+                        COALESCE(
+                            AVG(EXTRACT(EPOCH FROM processed_at - created_at) * 1000) FILTER (
+                                WHERE processed_at >= NOW() - INTERVAL '1 hour'
+                            ),
+                            0
+                        )::FLOAT8 AS avg_lag_ms_last_hour,
+                        COALESCE(
+                            MAX(EXTRACT(EPOCH FROM processed_at - created_at) * 1000) FILTER (
+                                WHERE processed_at >= NOW() - INTERVAL '1 hour'
+                            ),
+                            0
+                        )::FLOAT8 AS max_lag_ms_last_hour
+                    FROM outbox
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Integer, _>(max_attempts)
+                    .get_result::<OutboxLagModel>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl TransactionProvider for OutboxPostgres {
+    async fn transaction<R, E>(
+        &self,
+        f: Box<dyn for<'a> FnOnce(&'a mut PgConnection) -> Result<R, E> + Send + 'static>,
+    ) -> Result<R, E>
+    where
+        R: Send + 'static,
+        E: From<diesel::result::Error> + Send + 'static,
+    {
+        self.db_pool.transaction(f).await
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::progression::{AddBrawlerAchievementEntity, AddXpAwardEntity};
+use crate::domain::repositories::progression::ProgressionRepository;
+use crate::domain::value_object::progression_model::{
+    ProgressionStatsModel, UnlockedAchievementModel,
+};
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{brawler_achievements, xp_awards};
+
+pub struct ProgressionPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ProgressionPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ProgressionRepository for ProgressionPostgres {
+    async fn award_xp(&self, add_xp_award_entities: Vec<AddXpAwardEntity>) -> Result<usize> {
+        self.db_pool
+            .run(move |connection| {
+                // Awards are keyed by (brawler, mission, reason) so re-completing is a no-op
+                let inserted = diesel::insert_into(xp_awards::table)
+                    .values(&add_xp_award_entities)
+                    .on_conflict_do_nothing()
+                    .execute(connection)?;
+
+                Ok(inserted)
+            })
+            .await
+    }
+
+    async fn unlock(
+        &self,
+        add_brawler_achievement_entities: Vec<AddBrawlerAchievementEntity>,
+    ) -> Result<usize> {
+        self.db_pool
+            .run(move |connection| {
+                let inserted = diesel::insert_into(brawler_achievements::table)
+                    .values(&add_brawler_achievement_entities)
+                    .on_conflict_do_nothing()
+                    .execute(connection)?;
+
+                Ok(inserted)
+            })
+            .await
+    }
+
+    async fn get_stats(&self, brawler_id: i32) -> Result<ProgressionStatsModel> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT
+                        x.xp,
+                        brawler_level(x.xp) AS level,
+                        (SELECT COUNT(*) FROM crew_memberships cm
+                            INNER JOIN missions m ON m.id = cm.mission_id
+                            WHERE cm.brawler_id = b.id AND m.status = 'Completed' AND m.deleted_at IS NULL
+                        ) AS completed_count,
+                        (SELECT COUNT(*) FROM missions m
+                            WHERE m.chief_id = b.id AND m.status = 'Completed' AND m.deleted_at IS NULL
+                        ) AS led_count,
+                        (SELECT COUNT(*) FROM crew_memberships cm
+                            INNER JOIN missions m ON m.id = cm.mission_id
+                            WHERE cm.brawler_id = b.id AND m.deleted_at IS NULL
+                        ) AS joined_count
+                    FROM brawlers b
+                    CROSS JOIN LATERAL (
+                        SELECT COALESCE(SUM(amount), 0)::BIGINT AS xp
+                        FROM xp_awards
+                        WHERE brawler_id = b.id
+                    ) x
+                    WHERE b.id = $1
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .get_result::<ProgressionStatsModel>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn get_unlocked(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievementModel>> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT achievement, unlocked_at
+                    FROM brawler_achievements
+                    WHERE brawler_id = $1
+                    ORDER BY unlocked_at ASC
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .load::<UnlockedAchievementModel>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+}
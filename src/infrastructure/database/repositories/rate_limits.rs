@@ -0,0 +1,82 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    QueryableByName, RunQueryDsl,
+    sql_types::{Bool, Double, Varchar},
+};
+use std::sync::Arc;
+
+use crate::domain::repositories::rate_limits::RateLimitRepository;
+use crate::domain::value_object::rate_limit_model::{RateLimitDecision, RateLimitRule};
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+
+// One in this many takes also sweeps buckets idle for a day, which would be full again anyway
+const STALE_SWEEP_ONE_IN: u32 = 1000;
+
+#[derive(QueryableByName)]
+struct BucketRow {
+    #[diesel(sql_type = Double)]
+    tokens: f64,
+    #[diesel(sql_type = Bool)]
+    allowed: bool,
+}
+
+pub struct RateLimitPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RateLimitPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for RateLimitPostgres {
+    async fn take(&self, key: String, rule: RateLimitRule) -> Result<RateLimitDecision> {
+        let sweep = rand::random_ratio(1, STALE_SWEEP_ONE_IN);
+
+        self.db_pool
+            .run(move |connection| {
+                // Refill by the time since the last take, capped at the bucket size, then take a
+                // token if a whole one is there. The row lock of the upsert serializes instances.
+                let sql = r#"
+                    INSERT INTO rate_limit_buckets AS b (key, tokens, allowed, updated_at)
+                    VALUES ($1, $2 - 1, TRUE, NOW())
+                    ON CONFLICT (key) DO UPDATE SET
+                        tokens = CASE
+                            WHEN LEAST($2, b.tokens + EXTRACT(EPOCH FROM (NOW() - b.updated_at))::float8 * $3) >= 1
+                            THEN LEAST($2, b.tokens + EXTRACT(EPOCH FROM (NOW() - b.updated_at))::float8 * $3) - 1
+                            ELSE LEAST($2, b.tokens + EXTRACT(EPOCH FROM (NOW() - b.updated_at))::float8 * $3)
+                        END,
+                        allowed = LEAST($2, b.tokens + EXTRACT(EPOCH FROM (NOW() - b.updated_at))::float8 * $3) >= 1,
+                        updated_at = NOW()
+                    RETURNING tokens, allowed
+                "#;
+
+                let bucket = diesel::sql_query(sql)
+                    .bind::<Varchar, _>(key)
+                    .bind::<Double, _>(f64::from(rule.requests))
+                    .bind::<Double, _>(rule.refill_per_second())
+                    .get_result::<BucketRow>(connection)?;
+
+                if sweep {
+                    diesel::sql_query(
+                        "DELETE FROM rate_limit_buckets WHERE updated_at < NOW() - INTERVAL '1 day'",
+                    )
+                    .execute(connection)?;
+                }
+
+                Ok(RateLimitDecision {
+                    allowed: bucket.allowed,
+                    remaining: bucket.tokens.max(0.0) as u32,
+                    retry_after_seconds: if bucket.allowed {
+                        0
+                    } else {
+                        rule.retry_after_seconds(bucket.tokens)
+                    },
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    RunQueryDsl,
+    sql_types::{BigInt, Nullable, Varchar},
+};
+use std::sync::Arc;
+
+use crate::domain::repositories::tags::TagRepository;
+use crate::domain::value_object::tag_model::TagModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+
+pub struct TagPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl TagPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagPostgres {
+    async fn suggest(&self, prefix: Option<String>, limit: i64) -> Result<Vec<TagModel>> {
+        self.db_pool
+            .run(move |connection| {
+                let sql = r#"
+                    SELECT t.name, COUNT(*) AS usage_count
+                    FROM tags t
+                    INNER JOIN mission_tags mt ON mt.tag_id = t.id
+                    INNER JOIN missions m ON m.id = mt.mission_id AND m.deleted_at IS NULL
+                    WHERE $1 IS NULL OR t.name LIKE $1 || '%'
+                    GROUP BY t.name
+                    ORDER BY usage_count DESC, t.name ASC
+                    LIMIT $2
+                "#;
+
+                let result = diesel::sql_query(sql)
+                    .bind::<Nullable<Varchar>, _>(prefix)
+                    .bind::<BigInt, _>(limit)
+                    .load::<TagModel>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
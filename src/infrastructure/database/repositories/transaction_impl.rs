@@ -3,6 +3,7 @@ use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
 use async_trait::async_trait;
 use diesel::Connection;
 use diesel::PgConnection;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use tokio::task;
 
 #[async_trait]
@@ -17,7 +18,16 @@ impl TransactionProvider for PgPoolSquad {
     {
         let pool = self.clone();
         task::spawn_blocking(move || {
-            let mut conn = pool.get().expect("Failed to get connection from pool");
+            // An exhausted pool is a failed request, not a panic on a blocking thread
+            let mut conn = pool.get().map_err(|e| {
+                E::from(DieselError::DatabaseError(
+                    DatabaseErrorKind::UnableToSendCommand,
+                    Box::new(format!(
+                        "Failed to get database connection from pool: {}",
+                        e
+                    )),
+                ))
+            })?;
             conn.transaction(|conn| f(conn))
         })
         .await
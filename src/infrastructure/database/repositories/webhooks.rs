@@ -0,0 +1,254 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::entities::webhooks::{AddWebhookEntity, WebhookDeliveryEntity, WebhookEntity};
+use crate::domain::repositories::webhooks::WebhookRepository;
+use crate::domain::value_object::pagination::Pagination;
+use crate::domain::value_object::webhook_delivery_statuses::WebhookDeliveryStatuses;
+use crate::domain::value_object::webhook_model::WebhookDeliveryJobModel;
+use crate::infrastructure::database::postgresql_connection::{PgPoolRunner, PgPoolSquad};
+use crate::infrastructure::database::schema::{webhook_deliveries, webhooks};
+
+pub struct WebhookPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl WebhookPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookPostgres {
+    async fn add(&self, add_webhook_entity: AddWebhookEntity) -> Result<WebhookEntity> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::insert_into(webhooks::table)
+                    .values(&add_webhook_entity)
+                    .returning(WebhookEntity::as_returning())
+                    .get_result::<WebhookEntity>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn remove(&self, webhook_id: i32, owner_id: i32) -> Result<usize> {
+        self.db_pool
+            .run(move |connection| {
+                let result = diesel::delete(webhooks::table)
+                    .filter(webhooks::id.eq(webhook_id))
+                    .filter(webhooks::owner_id.eq(owner_id))
+                    .execute(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn find_by_id(&self, webhook_id: i32) -> Result<WebhookEntity> {
+        self.db_pool
+            .run(move |connection| {
+                let result = webhooks::table
+                    .filter(webhooks::id.eq(webhook_id))
+                    .select(WebhookEntity::as_select())
+                    .first::<WebhookEntity>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn gets_by_owner(&self, owner_id: i32) -> Result<Vec<WebhookEntity>> {
+        self.db_pool
+            .run(move |connection| {
+                let results = webhooks::table
+                    .filter(webhooks::owner_id.eq(owner_id))
+                    .order(webhooks::created_at.asc())
+                    .select(WebhookEntity::as_select())
+                    .load::<WebhookEntity>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn enqueue(
+        &self,
+        mission_id: i32,
+        event: String,
+        payload: serde_json::Value,
+    ) -> Result<usize> {
+        self.db_pool
+            .run(move |connection| {
+                // Only the mission's chief and crew hear about it
+                let sql = r#"
+                    INSERT INTO webhook_deliveries (webhook_id, event, payload)
+                    SELECT w.id, $1, $2
+                    FROM webhooks w
+                    WHERE w.is_active AND $1 = ANY(w.events)
+                      AND (
+                        w.owner_id = (SELECT chief_id FROM missions WHERE id = $3)
+                        OR EXISTS (
+                          SELECT 1
+                          FROM crew_memberships cm
+                          WHERE cm.mission_id = $3 AND cm.brawler_id = w.owner_id
+                        )
+                      )
+                "#;
+
+                let inserted = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Varchar, _>(event)
+                    .bind::<diesel::sql_types::Jsonb, _>(payload)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .execute(connection)?;
+
+                Ok(inserted)
+            })
+            .await
+    }
+
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_seconds: i64,
+    ) -> Result<Vec<WebhookDeliveryJobModel>> {
+        self.db_pool
+            .run(move |connection| {
+                // Pushing next_attempt_at forward acts as a lease, so concurrent dispatchers skip these
+                // rows and a crashed one lets them become due again once the lease runs out
+                let sql = r#"
+                    UPDATE webhook_deliveries d
+                    SET next_attempt_at = NOW() + make_interval(secs => $2::FLOAT8)
+                    FROM webhooks w
+                    WHERE w.id = d.webhook_id
+                      AND d.id IN (
+                        SELECT id
+                        FROM webhook_deliveries
+                        WHERE status = 'Pending' AND next_attempt_at <= NOW()
+                        ORDER BY next_attempt_at ASC
+                        LIMIT $1
+                        FOR UPDATE SKIP LOCKED
+                      )
+                    RETURNING d.id, d.webhook_id, w.url, w.secret, d.event, d.payload, d.attempts, d.created_at
+                "#;
+
+                let results = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::BigInt, _>(limit)
+                    .bind::<diesel::sql_types::BigInt, _>(lease_seconds)
+                    .load::<WebhookDeliveryJobModel>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn mark_delivered(&self, delivery_id: i32, status_code: i32) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(webhook_deliveries::table)
+                    .filter(webhook_deliveries::id.eq(delivery_id))
+                    .set((
+                        webhook_deliveries::status
+                            .eq(WebhookDeliveryStatuses::Delivered.to_string()),
+                        webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                        webhook_deliveries::last_status_code.eq(Some(status_code)),
+                        webhook_deliveries::last_error.eq(None::<String>),
+                        webhook_deliveries::delivered_at.eq(diesel::dsl::now),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn mark_retry(
+        &self,
+        delivery_id: i32,
+        status_code: Option<i32>,
+        error: String,
+        next_attempt_at: NaiveDateTime,
+    ) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(webhook_deliveries::table)
+                    .filter(webhook_deliveries::id.eq(delivery_id))
+                    .set((
+                        webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                        webhook_deliveries::last_status_code.eq(status_code),
+                        webhook_deliveries::last_error.eq(Some(error)),
+                        webhook_deliveries::next_attempt_at.eq(next_attempt_at),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn mark_failed(
+        &self,
+        delivery_id: i32,
+        status_code: Option<i32>,
+        error: String,
+    ) -> Result<()> {
+        self.db_pool
+            .run(move |connection| {
+                diesel::update(webhook_deliveries::table)
+                    .filter(webhook_deliveries::id.eq(delivery_id))
+                    .set((
+                        webhook_deliveries::status.eq(WebhookDeliveryStatuses::Failed.to_string()),
+                        webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                        webhook_deliveries::last_status_code.eq(status_code),
+                        webhook_deliveries::last_error.eq(Some(error)),
+                    ))
+                    .execute(connection)?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        pagination: &Pagination,
+    ) -> Result<Vec<WebhookDeliveryEntity>> {
+        let pagination = pagination.clone();
+
+        self.db_pool
+            .run(move |connection| {
+                let results = webhook_deliveries::table
+                    .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+                    .order((
+                        webhook_deliveries::created_at.desc(),
+                        webhook_deliveries::id.desc(),
+                    ))
+                    .limit(pagination.page_size())
+                    .offset(pagination.offset())
+                    .select(WebhookDeliveryEntity::as_select())
+                    .load::<WebhookDeliveryEntity>(connection)?;
+
+                Ok(results)
+            })
+            .await
+    }
+
+    async fn counting_deliveries(&self, webhook_id: i32) -> Result<i64> {
+        self.db_pool
+            .run(move |connection| {
+                let result = webhook_deliveries::table
+                    .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+                    .count()
+                    .get_result::<i64>(connection)?;
+
+                Ok(result)
+            })
+            .await
+    }
+}
@@ -7,6 +7,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    brawler_achievements (brawler_id, achievement) {
+        brawler_id -> Int4,
+        #[max_length = 50]
+        achievement -> Varchar,
+        unlocked_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -28,6 +37,45 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    calendar_tokens (brawler_id) {
+        brawler_id -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    conversation_members (conversation_id, brawler_id) {
+        conversation_id -> Int4,
+        brawler_id -> Int4,
+        joined_at -> Timestamp,
+        last_read_message_id -> Nullable<Int4>,
+        last_read_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    conversation_messages (id) {
+        id -> Int4,
+        conversation_id -> Int4,
+        sender_id -> Int4,
+        content -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    conversations (id) {
+        id -> Int4,
+        mission_id -> Nullable<Int4>,
+        #[max_length = 255]
+        name -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     crew_memberships (mission_id, brawler_id) {
         mission_id -> Int4,
@@ -36,6 +84,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    crew_departures (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 255]
+        mission_status -> Varchar,
+        left_at -> Timestamp,
+        abandoned -> Bool,
+    }
+}
+
 diesel::table! {
     friendships (brawler_id, friend_id) {
         brawler_id -> Int4,
@@ -58,6 +118,86 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mission_comments (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        content -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    mission_objectives (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        is_mandatory -> Bool,
+        completed_by -> Nullable<Int4>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_ratings (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        score -> Int2,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_templates (id) {
+        id -> Int4,
+        owner_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        #[max_length = 20]
+        comment_visibility -> Varchar,
+        require_objectives -> Bool,
+        #[max_length = 30]
+        category -> Nullable<Varchar>,
+        tags -> Array<Text>,
+        objectives -> Jsonb,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_series (id) {
+        id -> Int4,
+        chief_id -> Int4,
+        #[max_length = 20]
+        frequency -> Varchar,
+        weekdays -> Array<Int4>,
+        starts_at -> Timestamp,
+        next_occurrence_at -> Timestamp,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_tags (mission_id, tag_id) {
+        mission_id -> Int4,
+        tag_id -> Int4,
+    }
+}
+
 diesel::table! {
     missions (id) {
         id -> Int4,
@@ -70,18 +210,162 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        #[max_length = 20]
+        comment_visibility -> Varchar,
+        require_objectives -> Bool,
+        finished_at -> Nullable<Timestamp>,
+        deadline -> Nullable<Timestamp>,
+        #[max_length = 30]
+        category -> Nullable<Varchar>,
+        series_id -> Nullable<Int4>,
+        scheduled_for -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    notifications (id) {
+        id -> Int4,
+        recipient_id -> Int4,
+        actor_id -> Nullable<Int4>,
+        mission_id -> Nullable<Int4>,
+        #[max_length = 50]
+        kind -> Varchar,
+        content -> Text,
+        read_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    outbox (id) {
+        id -> Int8,
+        #[max_length = 50]
+        event_type -> Varchar,
+        payload -> Jsonb,
+        handled_by -> Array<Text>,
+        attempts -> Int4,
+        next_attempt_at -> Timestamp,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamp,
+        processed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    rate_limit_buckets (key) {
+        #[max_length = 200]
+        key -> Varchar,
+        tokens -> Float8,
+        allowed -> Bool,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        #[max_length = 32]
+        name -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        #[max_length = 50]
+        event -> Varchar,
+        payload -> Jsonb,
+        #[max_length = 20]
+        status -> Varchar,
+        attempts -> Int4,
+        next_attempt_at -> Timestamp,
+        last_status_code -> Nullable<Int4>,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamp,
+        delivered_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    webhooks (id) {
+        id -> Int4,
+        owner_id -> Int4,
+        url -> Text,
+        #[max_length = 128]
+        secret -> Varchar,
+        events -> Array<Text>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    xp_awards (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        mission_id -> Int4,
+        amount -> Int4,
+        #[max_length = 50]
+        reason -> Varchar,
+        created_at -> Timestamp,
     }
 }
 
+diesel::joinable!(brawler_achievements -> brawlers (brawler_id));
+diesel::joinable!(calendar_tokens -> brawlers (brawler_id));
+diesel::joinable!(conversation_members -> brawlers (brawler_id));
+diesel::joinable!(conversation_members -> conversations (conversation_id));
+diesel::joinable!(conversation_messages -> brawlers (sender_id));
+diesel::joinable!(conversation_messages -> conversations (conversation_id));
+diesel::joinable!(conversations -> missions (mission_id));
+diesel::joinable!(crew_departures -> brawlers (brawler_id));
+diesel::joinable!(crew_departures -> missions (mission_id));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(mission_comments -> brawlers (brawler_id));
+diesel::joinable!(mission_comments -> missions (mission_id));
+diesel::joinable!(mission_objectives -> brawlers (completed_by));
+diesel::joinable!(mission_objectives -> missions (mission_id));
+diesel::joinable!(mission_ratings -> missions (mission_id));
+diesel::joinable!(mission_series -> brawlers (chief_id));
+diesel::joinable!(mission_tags -> missions (mission_id));
+diesel::joinable!(mission_tags -> tags (tag_id));
+diesel::joinable!(mission_templates -> brawlers (owner_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(missions -> mission_series (series_id));
+diesel::joinable!(notifications -> missions (mission_id));
+diesel::joinable!(webhook_deliveries -> webhooks (webhook_id));
+diesel::joinable!(webhooks -> brawlers (owner_id));
+diesel::joinable!(xp_awards -> brawlers (brawler_id));
+diesel::joinable!(xp_awards -> missions (mission_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     Cen,
+    brawler_achievements,
     brawlers,
+    calendar_tokens,
+    conversation_members,
+    conversation_messages,
+    conversations,
+    crew_departures,
     crew_memberships,
     friendships,
     messages,
+    mission_comments,
+    mission_objectives,
+    mission_ratings,
+    mission_series,
+    mission_tags,
+    mission_templates,
     missions,
+    notifications,
+    outbox,
+    rate_limit_buckets,
+    tags,
+    webhook_deliveries,
+    webhooks,
+    xp_awards,
 );
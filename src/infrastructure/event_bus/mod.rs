@@ -0,0 +1,38 @@
+pub mod outbox_relay;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::events::{DomainEvent, EventSubscriber};
+
+pub struct EventBus {
+    subscribers: Vec<Arc<dyn EventSubscriber + Send + Sync>>,
+}
+
+impl EventBus {
+    pub fn new(subscribers: Vec<Arc<dyn EventSubscriber + Send + Sync>>) -> Self {
+        Self { subscribers }
+    }
+
+    // Subscribers listed in `handled_by` already succeeded on an earlier attempt and are
+    // skipped, so a retry only repeats the reactions that actually failed
+    pub async fn dispatch(
+        &self,
+        event: &DomainEvent,
+        handled_by: &[String],
+    ) -> Vec<(&'static str, Result<()>)> {
+        let mut outcomes = Vec::new();
+
+        for subscriber in &self.subscribers {
+            let name = subscriber.name();
+            if handled_by.iter().any(|handled| handled == name) {
+                continue;
+            }
+
+            outcomes.push((name, subscriber.handle(event).await));
+        }
+
+        outcomes
+    }
+}
@@ -0,0 +1,132 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::Result;
+use chrono::Utc;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::{
+    domain::{
+        constants::{
+            OUTBOX_BATCH_SIZE, OUTBOX_LEASE_SECONDS, OUTBOX_MAX_ATTEMPTS,
+            OUTBOX_POLL_INTERVAL_MILLIS, OUTBOX_RETRY_BASE_SECONDS,
+        },
+        entities::outbox::OutboxEntity,
+        events::DomainEvent,
+        repositories::outbox::OutboxRepository,
+    },
+    infrastructure::event_bus::EventBus,
+};
+
+fn retry_delay_seconds(attempts: i32) -> i64 {
+    OUTBOX_RETRY_BASE_SECONDS * 2_i64.pow((attempts - 1).max(0) as u32)
+}
+
+// Delivery is at least once: an event is only marked processed after every subscriber has
+// handled it, so a crash in between replays it and subscribers must tolerate duplicates
+pub struct OutboxRelay<T>
+where
+    T: OutboxRepository + Send + Sync,
+{
+    outbox_repository: Arc<T>,
+    event_bus: Arc<EventBus>,
+}
+
+impl<T> OutboxRelay<T>
+where
+    T: OutboxRepository + Send + Sync,
+{
+    pub fn new(outbox_repository: Arc<T>, event_bus: Arc<EventBus>) -> Self {
+        Self {
+            outbox_repository,
+            event_bus,
+        }
+    }
+
+    // A batch in progress is finished before the relay stops; unrelayed entries stay in the outbox
+    pub async fn run(self, shutdown: CancellationToken) {
+        info!("Outbox relay started");
+        let mut interval =
+            tokio::time::interval(Duration::from_millis(OUTBOX_POLL_INTERVAL_MILLIS));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown.cancelled() => break,
+            }
+
+            // Keep draining while batches come back full instead of waiting for the next tick
+            loop {
+                match self.relay_due().await {
+                    Ok(claimed) if claimed as i64 == OUTBOX_BATCH_SIZE => continue,
+                    Ok(_) => break,
+                    Err(e) => {
+                        warn!("Outbox relay failed: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+        info!("Outbox relay stopped");
+    }
+
+    pub async fn relay_due(&self) -> Result<usize> {
+        let entries = self
+            .outbox_repository
+            .claim_due(OUTBOX_BATCH_SIZE, OUTBOX_LEASE_SECONDS, OUTBOX_MAX_ATTEMPTS)
+            .await?;
+        let claimed = entries.len();
+
+        for entry in entries {
+            self.relay(entry).await?;
+        }
+
+        Ok(claimed)
+    }
+
+    async fn relay(&self, entry: OutboxEntity) -> Result<()> {
+        let errors = match serde_json::from_value::<DomainEvent>(entry.payload.clone()) {
+            Ok(event) => {
+                let mut errors = Vec::new();
+                for (subscriber, outcome) in
+                    self.event_bus.dispatch(&event, &entry.handled_by).await
+                {
+                    match outcome {
+                        Ok(()) => {
+                            self.outbox_repository
+                                .mark_handled(entry.id, subscriber.to_string())
+                                .await?;
+                        }
+                        Err(e) => errors.push(format!("{}: {}", subscriber, e)),
+                    }
+                }
+                errors
+            }
+            Err(e) => vec![format!("Unreadable payload: {}", e)],
+        };
+
+        if errors.is_empty() {
+            self.outbox_repository.mark_processed(entry.id).await?;
+            return Ok(());
+        }
+
+        let error = errors.join("; ");
+        if entry.attempts >= OUTBOX_MAX_ATTEMPTS {
+            warn!(
+                "Outbox event {} ({}) gave up after {} attempts: {}",
+                entry.id, entry.event_type, entry.attempts, error
+            );
+        } else {
+            warn!(
+                "Outbox event {} ({}) failed on attempt {}: {}",
+                entry.id, entry.event_type, entry.attempts, error
+            );
+        }
+
+        let next_attempt_at =
+            Utc::now().naive_utc() + chrono::Duration::seconds(retry_delay_seconds(entry.attempts));
+        self.outbox_repository
+            .mark_retry(entry.id, error, next_attempt_at)
+            .await
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use diesel::RunQueryDsl;
+use tokio::sync::Mutex;
+
+use crate::{
+    config::config_model::ImageBackend,
+    domain::value_object::health_model::{
+        HealthCheckModel, HealthCheckStatus, ReadinessReportModel,
+    },
+    infrastructure::{
+        cloudinary,
+        database::{
+            migrator,
+            postgresql_connection::{PgPoolRunner, PgPoolSquad},
+        },
+    },
+};
+
+// A check that takes longer than this counts as failed, so a hung dependency cannot hang the probe
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+// The Cloudinary Admin API is rate limited per hour, so its result is reused between probes
+const IMAGE_CHECK_TTL: Duration = Duration::from_secs(60);
+
+pub struct ReadinessProbe {
+    db_pool: Arc<PgPoolSquad>,
+    images: ImageBackend,
+    last_image_check: Mutex<Option<(Instant, HealthCheckModel)>>,
+}
+
+impl ReadinessProbe {
+    pub fn new(db_pool: Arc<PgPoolSquad>, images: ImageBackend) -> Self {
+        Self {
+            db_pool,
+            images,
+            last_image_check: Mutex::new(None),
+        }
+    }
+
+    // Checks run concurrently; the instance is ready only when none of them failed
+    pub async fn check(&self) -> ReadinessReportModel {
+        let (database, migrations, images) = tokio::join!(
+            self.check_database(),
+            self.check_migrations(),
+            self.check_images()
+        );
+        let checks = vec![database, migrations, images];
+
+        ReadinessReportModel {
+            ready: checks
+                .iter()
+                .all(|check| check.status != HealthCheckStatus::Failed),
+            checks,
+        }
+    }
+
+    async fn check_database(&self) -> HealthCheckModel {
+        timed(
+            "database",
+            self.db_pool.run(|conn| {
+                diesel::sql_query("SELECT 1").execute(conn)?;
+                Ok(())
+            }),
+        )
+        .await
+    }
+
+    async fn check_migrations(&self) -> HealthCheckModel {
+        timed("migrations", self.db_pool.run(migrator::verify)).await
+    }
+
+    async fn check_images(&self) -> HealthCheckModel {
+        let cloudinary_env = match &self.images {
+            ImageBackend::Cloudinary(cloudinary_env) => cloudinary_env,
+            ImageBackend::Mock | ImageBackend::Disabled => return skipped("images"),
+        };
+
+        let mut last_image_check = self.last_image_check.lock().await;
+        if let Some((checked_at, check)) = last_image_check.as_ref()
+            && checked_at.elapsed() < IMAGE_CHECK_TTL
+        {
+            return check.clone();
+        }
+
+        let check = timed("images", cloudinary::ping(cloudinary_env)).await;
+        *last_image_check = Some((Instant::now(), check.clone()));
+
+        check
+    }
+}
+
+async fn timed(name: &str, check: impl Future<Output = Result<()>>) -> HealthCheckModel {
+    let started = Instant::now();
+    let error = match tokio::time::timeout(CHECK_TIMEOUT, check).await {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(format!("{:#}", e)),
+        Err(_) => Some(format!("timed out after {:?}", CHECK_TIMEOUT)),
+    };
+
+    HealthCheckModel {
+        name: name.to_string(),
+        status: if error.is_none() {
+            HealthCheckStatus::Ok
+        } else {
+            HealthCheckStatus::Failed
+        },
+        latency_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        error,
+    }
+}
+
+fn skipped(name: &str) -> HealthCheckModel {
+    HealthCheckModel {
+        name: name.to_string(),
+        status: HealthCheckStatus::Skipped,
+        latency_ms: 0,
+        error: None,
+    }
+}
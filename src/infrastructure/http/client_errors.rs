@@ -0,0 +1,83 @@
+use std::error::Error as StdError;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+// Use cases report validation failures and infrastructure failures through the same anyhow::Error.
+// Validation messages are written for the client and go out as 400; anything raised by the
+// database, the pool, a blocking task or an outbound call is a 500, which hide_internal_errors
+// replaces with a generic body in Production
+pub fn client_error(e: &anyhow::Error) -> Response {
+    match infrastructure_status(e) {
+        Some(StatusCode::NOT_FOUND) => (StatusCode::NOT_FOUND, "Record not found").into_response(),
+        Some(StatusCode::CONFLICT) => (StatusCode::CONFLICT, "Already exists").into_response(),
+        Some(status) => (status, format!("{:#}", e)).into_response(),
+        None => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+fn infrastructure_status(e: &anyhow::Error) -> Option<StatusCode> {
+    e.chain().find_map(|cause| {
+        if let Some(diesel_error) = cause.downcast_ref::<DieselError>() {
+            return Some(diesel_status(diesel_error));
+        }
+
+        is_infrastructure(cause).then_some(StatusCode::INTERNAL_SERVER_ERROR)
+    })
+}
+
+fn diesel_status(e: &DieselError) -> StatusCode {
+    match e {
+        DieselError::NotFound => StatusCode::NOT_FOUND,
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => StatusCode::CONFLICT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn is_infrastructure(cause: &(dyn StdError + 'static)) -> bool {
+    cause.is::<diesel::r2d2::PoolError>()
+        || cause.is::<tokio::task::JoinError>()
+        || cause.is::<reqwest::Error>()
+        || cause.is::<std::io::Error>()
+        || cause.is::<prometheus::Error>()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{Context, anyhow};
+
+    use super::*;
+
+    #[test]
+    fn validation_errors_keep_their_message() {
+        let response = client_error(&anyhow!("Title must not be empty"));
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn database_errors_become_internal() {
+        let e = anyhow::Error::from(DieselError::BrokenTransactionManager);
+        assert_eq!(client_error(&e).status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let e = Err::<(), _>(std::io::Error::other("connection reset"))
+            .context("Failed to get database connection from pool")
+            .unwrap_err();
+        assert_eq!(client_error(&e).status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn missing_rows_and_duplicates_map_to_their_status() {
+        let e = anyhow::Error::from(DieselError::NotFound);
+        assert_eq!(client_error(&e).status(), StatusCode::NOT_FOUND);
+
+        let e = anyhow::Error::from(DieselError::DatabaseError(
+            DatabaseErrorKind::UniqueViolation,
+            Box::new("duplicate key value violates unique constraint".to_string()),
+        ));
+        assert_eq!(client_error(&e).status(), StatusCode::CONFLICT);
+    }
+}
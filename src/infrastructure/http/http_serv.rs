@@ -1,32 +1,66 @@
-use std::{net::SocketAddr, sync::Arc, time::Duration};
+use std::{future::IntoFuture, net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::Result;
 use axum::{
-    Router,
+    Extension, Router,
+    body::Body,
     http::{
-        Method, StatusCode,
+        HeaderName, HeaderValue, Method, Request, StatusCode,
         header::{AUTHORIZATION, CONTENT_TYPE},
     },
+    middleware,
     routing::get,
 };
-use tokio::net::TcpListener;
+use tokio::{net::TcpListener, task::JoinSet};
+use tokio_util::sync::CancellationToken;
 use tower_http::{
-    cors::{Any, CorsLayer},
+    cors::{AllowOrigin, Any, CorsLayer},
     limit::RequestBodyLimitLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     services::{ServeDir, ServeFile},
     timeout::TimeoutLayer,
-    trace::TraceLayer,
+    trace::{DefaultOnResponse, TraceLayer},
 };
-use tracing::info;
+use tracing::{Level, Span, error, info, info_span, warn};
 
 use crate::{
-    config::config_model::DotEnvyConfig,
+    application::subscribers::{
+        metrics::MetricsSubscriber, mission_channels::MissionChannelSubscriber,
+        notifications::NotificationSubscriber, progression::ProgressionSubscriber,
+        webhooks::WebhookSubscriber,
+    },
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    config::{config_model::AppConfig, stage::Stage},
     infrastructure::{
-        database::postgresql_connection::PgPoolSquad,
-        http::routers::{self},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                conversations::ConversationPostgres, mission_management::MissionManagementPostgres,
+                mission_objectives::MissionObjectivePostgres,
+                mission_series::MissionSeriesPostgres, mission_templates::MissionTemplatePostgres,
+                notifications::NotificationPostgres, outbox::OutboxPostgres,
+                progression::ProgressionPostgres, webhooks::WebhookPostgres,
+            },
+        },
+        event_bus::{EventBus, outbox_relay::OutboxRelay},
+        health::ReadinessProbe,
+        http::{
+            middlewares::{
+                errors::hide_internal_errors, metrics::track_http, rate_limit::rate_limit,
+            },
+            routers::{self},
+        },
+        metrics::Metrics,
+        rate_limit::{RateLimitGroup, RateLimiter},
+        realtime::notification_hub::NotificationHub,
+        scheduler::MissionScheduler,
+        telemetry,
+        webhooks::WebhookDispatcher,
     },
 };
 
+const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
 fn static_serve() -> Router {
     let dir = "statics";
 
@@ -35,43 +69,195 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn api_serve(
+    db_pool: Arc<PgPoolSquad>,
+    notification_hub: Arc<NotificationHub>,
+    config: Arc<AppConfig>,
+) -> Router {
+    let rate_limiter = Arc::new(RateLimiter::new(&config.rate_limits, Arc::clone(&db_pool)));
+
     Router::new()
-        .nest("/brawlers", routers::brawlers::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/brawlers",
+            routers::brawlers::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&config),
+                Arc::clone(&rate_limiter),
+            ),
+        )
         .nest(
             "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
+            routers::authentication::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&config),
+                Arc::clone(&rate_limiter),
+            ),
         )
-        .nest("/util", routers::default_routers::routes())
+        .nest("/util", routers::default_routers::routes(&config.stage))
         .nest(
             "/missions",
             routers::mission_management::routes(Arc::clone(&db_pool)),
         )
         .nest(
             "/crew_operation",
-            routers::crew_operation::routes(Arc::clone(&db_pool)),
+            routers::crew_operation::routes(Arc::clone(&db_pool), Arc::clone(&config)),
         )
         .nest(
             "/mission",
-            routers::mission_operation::routes(Arc::clone(&db_pool)),
+            routers::mission_operation::routes(Arc::clone(&db_pool), Arc::clone(&config)),
         )
         .nest(
             "/view",
-            routers::mission_viewing::routes(Arc::clone(&db_pool)),
+            routers::mission_viewing::routes(Arc::clone(&db_pool), Arc::clone(&config)),
+        )
+        .nest(
+            "/comments",
+            routers::mission_comments::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/objectives",
+            routers::mission_objectives::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/ratings",
+            routers::mission_ratings::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/progression",
+            routers::progression::routes(Arc::clone(&db_pool)),
+        )
+        .nest("/webhooks", routers::webhooks::routes(Arc::clone(&db_pool)))
+        .nest("/tags", routers::tags::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/series",
+            routers::mission_series::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/templates",
+            routers::mission_templates::routes(Arc::clone(&db_pool)),
+        )
+        .nest("/calendar", routers::calendar::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/notifications",
+            routers::notifications::routes(Arc::clone(&db_pool), Arc::clone(&notification_hub)),
         )
         .merge(routers::friendships::routes(Arc::clone(&db_pool)))
-        .merge(routers::messages::routes(Arc::clone(&db_pool)))
+        .merge(routers::messages::routes(
+            Arc::clone(&db_pool),
+            Arc::clone(&rate_limiter),
+        ))
+        .merge(routers::channels::routes(Arc::clone(&db_pool)))
         .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+        // Before auth runs, so this reads the bearer token itself to count per brawler
+        .layer(middleware::from_fn_with_state(
+            (rate_limiter, RateLimitGroup::Api),
+            rate_limit,
+        ))
 }
 
-pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+pub async fn start(config: Arc<AppConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+    let notification_hub = Arc::new(NotificationHub::new());
+    let metrics = Arc::new(Metrics::new()?);
+    let shutdown = CancellationToken::new();
+    let mut workers = JoinSet::new();
+
+    let event_bus = Arc::new(EventBus::new(vec![
+        Arc::new(MissionChannelSubscriber::new(Arc::new(
+            ConversationPostgres::new(Arc::clone(&db_pool)),
+        ))),
+        Arc::new(ProgressionSubscriber::new(Arc::new(
+            ProgressionPostgres::new(Arc::clone(&db_pool)),
+        ))),
+        Arc::new(NotificationSubscriber::new(Arc::new(
+            NotificationPostgres::new(Arc::clone(&db_pool), Arc::clone(&notification_hub)),
+        ))),
+        Arc::new(WebhookSubscriber::new(Arc::new(WebhookPostgres::new(
+            Arc::clone(&db_pool),
+        )))),
+        Arc::new(MetricsSubscriber::new(Arc::clone(&metrics))),
+    ]));
+
+    let outbox_relay = OutboxRelay::new(
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+        event_bus,
+    );
+    workers.spawn(outbox_relay.run(shutdown.clone()));
+
+    let webhook_dispatcher =
+        WebhookDispatcher::new(Arc::new(WebhookPostgres::new(Arc::clone(&db_pool))))?;
+    workers.spawn(webhook_dispatcher.run(shutdown.clone()));
+
+    let mission_scheduler = MissionScheduler::new(Arc::new(MissionSeriesUseCase::new(
+        Arc::new(MissionSeriesPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionObjectivePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+    )));
+    workers.spawn(mission_scheduler.run(shutdown.clone()));
+
+    let metrics_router = routers::metrics::routes(
+        Arc::clone(&db_pool),
+        Arc::clone(&notification_hub),
+        Arc::clone(&metrics),
+        config.metrics.token.clone(),
+    );
+    let metrics_on_api_port = match config.metrics.port {
+        Some(port) => {
+            let listener = TcpListener::bind(SocketAddr::from(([0, 0, 0, 0], port))).await?;
+            info!("Metrics served on port {}", port);
+            let shutdown = shutdown.clone();
+            workers.spawn(async move {
+                if let Err(e) = axum::serve(listener, metrics_router)
+                    .with_graceful_shutdown(shutdown.cancelled_owned())
+                    .await
+                {
+                    error!("Metrics server stopped: {}", e);
+                }
+            });
+            None
+        }
+        None if config.metrics.token.is_some() => Some(metrics_router),
+        None => {
+            info!("Metrics disabled: set METRICS_PORT or METRICS_TOKEN to expose /metrics");
+            None
+        }
+    };
+
     let app = Router::new()
         .merge(static_serve())
-        .nest("/api", api_serve(db_pool))
-        .route("/health_check", get(routers::default_routers::health_check))
-        // .fallback(default_router::health_check)
-        // .route("/health_check", get(default_router::health_check)
-        //.route("/make_error", get(default_router::make_error))
+        .nest(
+            "/api",
+            api_serve(
+                Arc::clone(&db_pool),
+                Arc::clone(&notification_hub),
+                Arc::clone(&config),
+            ),
+        )
+        .merge(metrics_on_api_port.unwrap_or_default())
+        .route("/health_check", get(routers::default_routers::health_check));
+    // .fallback(default_router::health_check)
+    // .route("/health_check", get(default_router::health_check)
+    //.route("/make_error", get(default_router::make_error))
+
+    // Innermost, so the original error is logged inside the request span
+    let app = if config.stage == Stage::Production {
+        app.layer(middleware::from_fn(hide_internal_errors))
+    } else {
+        app
+    };
+
+    // Merged after the error layer: a degraded readiness report is a 503 the orchestrator must see
+    let readiness_probe = Arc::new(ReadinessProbe::new(
+        Arc::clone(&db_pool),
+        config.images.clone(),
+    ));
+    let app = app.nest(
+        "/health",
+        routers::health::routes(readiness_probe, config.stage != Stage::Production),
+    );
+
+    let app = app
         .layer(TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(config.server.timeout),
@@ -89,25 +275,127 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
                     Method::DELETE,
                     Method::OPTIONS,
                 ])
-                .allow_origin(Any)
-                .allow_headers([AUTHORIZATION, CONTENT_TYPE]),
+                .allow_origin(allowed_origins(&config.server.cors_origins)?)
+                .allow_headers([AUTHORIZATION, CONTENT_TYPE, X_REQUEST_ID])
+                .expose_headers([
+                    X_REQUEST_ID,
+                    routers::mission_viewing::X_TOTAL_COUNT,
+                    routers::mission_viewing::X_PAGE,
+                    routers::mission_viewing::X_PAGE_SIZE,
+                ]),
         )
-        .layer(TraceLayer::new_for_http());
+        .layer(middleware::from_fn_with_state(metrics, track_http))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(request_span)
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
+        )
+        // A caller's X-Request-Id is kept, otherwise one is generated; either way it is echoed back
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .layer(Extension(Arc::clone(&config)));
 
     let addr = SocketAddr::from(([0, 0, 0, 0], config.server.port));
     let listener = TcpListener::bind(addr).await?;
 
+    // On SIGTERM or ctrl + c the listener stops accepting and notification streams are closed,
+    // then in-flight requests get the drain timeout to finish
+    tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move {
+            shutdown_signal().await;
+            shutdown.cancel();
+            notification_hub.close();
+        }
+    });
+
+    let drain_timeout = Duration::from_secs(config.server.shutdown_timeout);
     info!("Server start on port {}", config.server.port);
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    let mut server = Box::pin(
+        // The peer address is the rate limit key for anonymous requests
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown.clone().cancelled_owned())
+        .into_future(),
+    );
+
+    tokio::select! {
+        served = &mut server => served?,
+        _ = async {
+            shutdown.cancelled().await;
+            tokio::time::sleep(drain_timeout).await;
+        } => {
+            warn!(
+                "Drain timeout of {:?} elapsed, dropping the remaining connections",
+                drain_timeout
+            );
+        }
+    }
+
+    // Drops the routers and the pool handles they hold, along with any connection left over
+    drop(server);
+    info!("HTTP server stopped");
+
+    // Workers finish the batch they are on; whatever is left is picked up by the next instance
+    let stopped = tokio::time::timeout(drain_timeout, async {
+        while workers.join_next().await.is_some() {}
+    })
+    .await;
+    if stopped.is_err() {
+        warn!("Background workers did not stop within {:?}", drain_timeout);
+        workers.abort_all();
+    }
+
+    // Last, once nothing can check out a connection anymore
+    info!("Closing database pool");
+    drop(db_pool);
 
     Ok(())
 }
 
+// brawler_id stays empty until the auth middleware records it
+fn request_span(req: &Request<Body>) -> Span {
+    let request_id = req
+        .headers()
+        .get(X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    info_span!(
+        "request",
+        method = %req.method(),
+        path = %telemetry::loggable_path(req.uri().path()),
+        request_id = %request_id,
+        brawler_id = tracing::field::Empty,
+    )
+}
+
+fn allowed_origins(origins: &[String]) -> Result<AllowOrigin> {
+    if origins.is_empty() {
+        return Ok(AllowOrigin::from(Any));
+    }
+
+    let origins = origins
+        .iter()
+        .map(|origin| HeaderValue::from_str(origin))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(AllowOrigin::list(origins))
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async { tokio::signal::ctrl_c().await.expect("Fail ctrl + c") };
 
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Fail to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
     let terminate = std::future::pending::<()>();
 
     tokio::select! {
@@ -1,5 +1,6 @@
 use crate::infrastructure;
-use crate::config::config_loader::get_user_secret as get_user_secret_env;
+use crate::config::config_model::AppConfig;
+use std::sync::Arc;
 use axum::{http::{Request, StatusCode, header}, middleware::Next, body::Body, response::Response};
 use anyhow::Result;
 
@@ -10,14 +11,42 @@ pub async fn authorization (mut req: Request<Body>, next: Next) -> Result<Respon
         .and_then(|value| value.to_str().ok())
         .ok_or(StatusCode::UNAUTHORIZED)?;
 
+    let brawler_id = verify_bearer(&req, auth_header)?;
+
+    req.extensions_mut().insert::<i32>(brawler_id);
+
+    Ok(next.run(req).await)
+    
+    }
+
+// Anonymous requests pass through with `None`; a token that is present but invalid is still rejected.
+pub async fn optional_authorization (mut req: Request<Body>, next: Next) -> Result<Response, StatusCode> {
+    let brawler_id = match req.headers().get(header::AUTHORIZATION) {
+        Some(value) => {
+            let auth_header = value.to_str().map_err(|_| StatusCode::UNAUTHORIZED)?;
+            Some(verify_bearer(&req, auth_header)?)
+        }
+        None => None,
+    };
+
+    req.extensions_mut().insert::<Option<i32>>(brawler_id);
+
+    Ok(next.run(req).await)
+    }
+
+// The config is put on every request by a layer in http_serv
+fn verify_bearer(req: &Request<Body>, auth_header: &str) -> Result<i32, StatusCode> {
     let token = auth_header
         .strip_prefix("Bearer ")
         .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    let secret_env = get_user_secret_env().map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let config = req
+        .extensions()
+        .get::<Arc<AppConfig>>()
+        .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let claims = 
-        infrastructure::jwt::verify_token(secret_env, token.to_string())
+        infrastructure::jwt::verify_token(config.jwt.secret.clone(), token.to_string())
             .map_err(|_| StatusCode::UNAUTHORIZED)?;
     
     let brawler_id = claims
@@ -25,8 +54,15 @@ pub async fn authorization (mut req: Request<Body>, next: Next) -> Result<Respon
         .parse::<i32>()
         .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    req.extensions_mut().insert::<i32>(brawler_id);
+    // Fills the field left empty by the request span in http_serv, so every later log line carries it
+    tracing::Span::current().record("brawler_id", brawler_id);
 
-    Ok(next.run(req).await)
-    
+    Ok(brawler_id)
+    }
+
+// Who is asking, for callers that never reject, such as the rate limiter in front of the routers
+pub fn bearer_brawler_id(req: &Request<Body>) -> Option<i32> {
+    let auth_header = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+
+    verify_bearer(req, auth_header).ok()
     }
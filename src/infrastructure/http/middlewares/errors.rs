@@ -0,0 +1,45 @@
+use axum::{
+    body::{Body, to_bytes},
+    extract::MatchedPath,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::error;
+
+use crate::infrastructure::telemetry;
+
+// Production only: 5xx bodies carry database and upstream errors, so they are logged here and
+// replaced with a generic message before leaving the server
+pub async fn hide_internal_errors(req: Request<Body>, next: Next) -> Response {
+    let method = req.method().clone();
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| telemetry::loggable_path(req.uri().path()));
+    let response = next.run(req).await;
+
+    if !response.status().is_server_error() {
+        return response;
+    }
+
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap_or_default();
+    error!(
+        "{} {} failed with {}: {}",
+        method,
+        path,
+        status,
+        String::from_utf8_lossy(&body)
+    );
+
+    let message = match status {
+        StatusCode::SERVICE_UNAVAILABLE => "Service unavailable",
+        StatusCode::GATEWAY_TIMEOUT => "Upstream timed out",
+        _ => "Internal server error",
+    };
+    (status, message).into_response()
+}
@@ -1 +1,4 @@
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod errors;
+pub mod metrics;
+pub mod rate_limit;
\ No newline at end of file
@@ -0,0 +1,71 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::warn;
+
+use crate::infrastructure::{
+    http::middlewares::auth::bearer_brawler_id,
+    rate_limit::{RateLimitGroup, RateLimiter},
+};
+
+// Authenticated requests are counted per brawler, so brawlers behind one NAT do not share a
+// budget; anonymous ones per client IP. A failing store lets the request through.
+pub async fn rate_limit(
+    State((limiter, group)): State<(Arc<RateLimiter>, RateLimitGroup)>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let client = client_key(&limiter, &req);
+
+    match limiter.take(group, &client).await {
+        Ok(Some(decision)) if !decision.allowed => (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(
+                header::RETRY_AFTER,
+                decision.retry_after_seconds.to_string(),
+            )],
+            "Too many requests",
+        )
+            .into_response(),
+        Ok(_) => next.run(req).await,
+        Err(e) => {
+            warn!("Rate limit check failed for {}: {}", client, e);
+            next.run(req).await
+        }
+    }
+}
+
+fn client_key(limiter: &RateLimiter, req: &Request<Body>) -> String {
+    let brawler_id = req
+        .extensions()
+        .get::<i32>()
+        .copied()
+        .or_else(|| req.extensions().get::<Option<i32>>().copied().flatten())
+        .or_else(|| bearer_brawler_id(req));
+    if let Some(brawler_id) = brawler_id {
+        return format!("brawler:{}", brawler_id);
+    }
+
+    // The proxy appends the address it saw, so only the last entry can be trusted
+    let forwarded = limiter
+        .trust_forwarded_for()
+        .then(|| req.headers().get("x-forwarded-for"))
+        .flatten()
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+        .map(|ip| ip.trim().to_string())
+        .filter(|ip| !ip.is_empty());
+    let ip = forwarded.or_else(|| {
+        req.extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+    });
+
+    format!("ip:{}", ip.unwrap_or_else(|| "unknown".to_string()))
+}
@@ -1,3 +1,4 @@
+pub mod client_errors;
 pub mod http_serv;
 pub mod middlewares;
 pub mod routers;
@@ -4,8 +4,9 @@ use axum::{Json, Router, extract::State, http::StatusCode, response::IntoRespons
 
 use crate::{
     application::use_cases::authentication::AuthenticationUseCase,
+    config::config_model::AppConfig,
     domain::repositories::brawlers::BrawlerRepository,
-    infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres}, jwt::authentication_model::LoginModel},
+    infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres}, http::{client_errors::client_error, middlewares::rate_limit::rate_limit}, jwt::authentication_model::LoginModel, rate_limit::{RateLimitGroup, RateLimiter}},
 };
 
 pub async fn login<T>(
@@ -17,16 +18,17 @@ where
 {
     match user_case.login(model).await {
         Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<AppConfig>, rate_limiter: Arc<RateLimiter>) -> Router {
     let repository = BrawlerPostgres::new(db_pool.clone());
-    let use_case = AuthenticationUseCase::new(Arc::new(repository));
+    let use_case = AuthenticationUseCase::new(Arc::new(repository), config);
 
     Router::new()
-        .route("/login", post(login))
+        // Counted per client IP, which slows down password guessing across usernames
+        .route("/login", post(login).layer(axum::middleware::from_fn_with_state((rate_limiter, RateLimitGroup::Auth), rate_limit)))
         .with_state(Arc::new(use_case))
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use axum::{
     Extension, Json, Router,
-    extract::State,
+    extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
@@ -10,19 +10,26 @@ use axum::{
 
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
+    config::config_model::AppConfig,
     domain::{
         repositories::brawlers::BrawlerRepository,
         value_object::{brawler_model::RegisterBrawlerModel, upload_image::UploadAvatar},
     },
     infrastructure::{
         database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        http::middlewares::auth::authorization,
+        http::client_errors::client_error,
+        http::middlewares::{auth::authorization, rate_limit::rate_limit},
+        rate_limit::{RateLimitGroup, RateLimiter},
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    config: Arc<AppConfig>,
+    rate_limiter: Arc<RateLimiter>,
+) -> Router {
     let brawlers_repository = BrawlerPostgres::new(db_pool);
-    let brawlers_use_case = BrawlersUseCase::new(Arc::new(brawlers_repository));
+    let brawlers_use_case = BrawlersUseCase::new(Arc::new(brawlers_repository), config);
 
     let protected_router = Router::new()
         .route("/avatar", post(upload_avatar))
@@ -31,7 +38,14 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route_layer(axum::middleware::from_fn(authorization));
 
     Router::new()
-        .route("/register", post(register))
+        .route(
+            "/register",
+            post(register).layer(axum::middleware::from_fn_with_state(
+                (rate_limiter, RateLimitGroup::Auth),
+                rate_limit,
+            )),
+        )
+        .route("/profile/{brawler_id}", get(get_profile))
         .merge(protected_router)
         .with_state(Arc::new(brawlers_use_case))
 }
@@ -45,7 +59,20 @@ where
 {
     match brawlers_use_case.get_my_missions(brawler_id).await {
         Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_profile<T>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    match brawlers_use_case.get_profile(brawler_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
@@ -58,7 +85,7 @@ where
 {
     match brawlers_use_case.register(register_brawler_model).await {
         Ok(passport) => (StatusCode::CREATED, Json(passport)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
@@ -75,7 +102,7 @@ where
         .await
     {
         Ok(uploaded_image) => (StatusCode::CREATED, Json(uploaded_image)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
@@ -97,6 +124,6 @@ where
         .await
     {
         Ok(_) => (StatusCode::OK, "Name updated successfully").into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::calendar::CalendarUseCase,
+    config::config_model::AppConfig,
+    infrastructure::{
+        calendar,
+        database::postgresql_connection::PgPoolSquad,
+        database::repositories::{
+            calendar::CalendarPostgres, mission_viewing::MissionViewingPostgres,
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+
+type CalendarFeedUseCase = CalendarUseCase<CalendarPostgres, MissionViewingPostgres>;
+
+const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+pub async fn rotate_token(
+    State(use_case): State<Arc<CalendarFeedUseCase>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse {
+    match use_case.rotate_token(brawler_id).await {
+        Ok(token) => (StatusCode::CREATED, Json(token)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn revoke_token(
+    State(use_case): State<Arc<CalendarFeedUseCase>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse {
+    match use_case.revoke_token(brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn feed(
+    State(use_case): State<Arc<CalendarFeedUseCase>>,
+    Extension(config): Extension<Arc<AppConfig>>,
+    Path(file): Path<String>,
+) -> impl IntoResponse {
+    let token = file.strip_suffix(".ics").unwrap_or(&file);
+
+    match use_case.feed(token).await {
+        Ok(missions) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, CALENDAR_CONTENT_TYPE)],
+            calendar::render("My missions", &missions, &config.missions),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mission(
+    State(use_case): State<Arc<CalendarFeedUseCase>>,
+    Extension(config): Extension<Arc<AppConfig>>,
+    Path(file): Path<String>,
+) -> impl IntoResponse {
+    let Ok(mission_id) = file.strip_suffix(".ics").unwrap_or(&file).parse::<i32>() else {
+        return (StatusCode::NOT_FOUND, "Mission not found".to_string()).into_response();
+    };
+
+    match use_case.mission(mission_id).await {
+        Ok(mission) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, CALENDAR_CONTENT_TYPE.to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"mission-{}.ics\"", mission_id),
+                ),
+            ],
+            calendar::render(
+                &mission.name,
+                std::slice::from_ref(&mission),
+                &config.missions,
+            ),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let use_case = CalendarUseCase::new(
+        Arc::new(CalendarPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    // Feed URLs are opened by calendar apps that cannot send a bearer token; the secret in the path is the credential
+    Router::new()
+        .route("/token", post(rotate_token).delete(revoke_token))
+        .route_layer(middleware::from_fn(authorization))
+        .route("/feed/{file}", get(feed))
+        .route("/missions/{file}", get(mission))
+        .with_state(Arc::new(use_case))
+}
@@ -0,0 +1,90 @@
+use crate::{
+    application::use_cases::conversations::ConversationUseCase,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::conversations::ConversationPostgres,
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let conversation_repository = ConversationPostgres::new(db_pool);
+    let conversation_use_case = ConversationUseCase::new(Arc::new(conversation_repository));
+
+    Router::new()
+        .route("/channels", get(get_channels))
+        .route("/channels/mission/{mission_id}", get(get_mission_channel))
+        .route("/channels/{conversation_id}/messages", get(get_messages))
+        .route("/channels/{conversation_id}/send", post(send_message))
+        .layer(axum::middleware::from_fn(authorization))
+        .with_state(Arc::new(conversation_use_case))
+}
+
+#[derive(Deserialize)]
+pub struct SendChannelMessageRequest {
+    pub content: String,
+}
+
+pub async fn get_channels(
+    State(conversation_use_case): State<Arc<ConversationUseCase<ConversationPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse {
+    match conversation_use_case.get_channels(brawler_id).await {
+        Ok(channels) => (StatusCode::OK, Json(channels)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_mission_channel(
+    State(conversation_use_case): State<Arc<ConversationUseCase<ConversationPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match conversation_use_case
+        .get_mission_channel(mission_id, brawler_id)
+        .await
+    {
+        Ok(channel) => (StatusCode::OK, Json(channel)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn send_message(
+    State(conversation_use_case): State<Arc<ConversationUseCase<ConversationPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+    Json(payload): Json<SendChannelMessageRequest>,
+) -> impl IntoResponse {
+    match conversation_use_case
+        .send_message(conversation_id, brawler_id, payload.content)
+        .await
+    {
+        Ok(msg) => (StatusCode::OK, Json(msg)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_messages(
+    State(conversation_use_case): State<Arc<ConversationUseCase<ConversationPostgres>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(conversation_id): Path<i32>,
+) -> impl IntoResponse {
+    match conversation_use_case
+        .get_messages(conversation_id, brawler_id)
+        .await
+    {
+        Ok(msgs) => (StatusCode::OK, Json(msgs)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
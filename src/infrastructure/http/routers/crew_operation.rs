@@ -10,29 +10,35 @@ use axum::{
 
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
+    config::config_model::AppConfig,
     domain::repositories::{
         crew_oparation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+        outbox::OutboxRepository, transaction_provider::TransactionProvider,
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
                 crew_participation::CrewParticipationPostgres,
-                mission_viewing::MissionViewingPostgres,
+                mission_viewing::MissionViewingPostgres, outbox::OutboxPostgres,
             },
         },
+        http::client_errors::client_error,
         http::middlewares::auth::authorization,
     },
 };
 
-pub async fn join<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+type UseCaseState<T1, T2, T3> = State<Arc<CrewOperationUseCase<T1, T2, T3>>>;
+
+pub async fn join<T1, T2, T3>(
+    State(crew_operation_use_case): UseCaseState<T1, T2, T3>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
     match crew_operation_use_case.join(mission_id, brawler_id).await {
         Ok(_) => (
@@ -40,18 +46,19 @@ where
             format!("Brawler({}) join Mission({})", brawler_id, mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub async fn leave<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn leave<T1, T2, T3>(
+    State(crew_operation_use_case): UseCaseState<T1, T2, T3>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
     match crew_operation_use_case.leave(mission_id, brawler_id).await {
         Ok(_) => (
@@ -59,17 +66,19 @@ where
             format!("Brawler({}) has leaved Mission({})", brawler_id, mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<AppConfig>) -> Router {
     let crew_operation_repository = CrewParticipationPostgres::new(Arc::clone(&db_pool));
     let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
 
     let use_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
         Arc::new(mission_viewing_repository),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+        config,
     );
 
     Router::new()
@@ -1,5 +1,7 @@
 use axum::{Router, extract::Path, http::StatusCode, response::IntoResponse, routing::get};
 
+use crate::config::stage::Stage;
+
 pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, " All Right, I'am Good").into_response()
 }
@@ -9,8 +11,13 @@ pub async fn make_error(Path(code): Path<u16>) -> impl IntoResponse {
     (status_code, code.to_string()).into_response()
 }
 
-pub fn routes() -> Router {
-    Router::new()
-        .route("/make-error/{code}", get(make_error))
-        .route("/health-check", get(health_check))
+pub fn routes(stage: &Stage) -> Router {
+    let router = Router::new().route("/health-check", get(health_check));
+
+    // Only useful for trying out error handling on a developer machine
+    if *stage == Stage::Local {
+        return router.route("/make-error/{code}", get(make_error));
+    }
+
+    router
 }
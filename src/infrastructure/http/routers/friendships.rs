@@ -4,8 +4,11 @@ use crate::{
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
-            repositories::{brawlers::BrawlerPostgres, friendships::FriendshipPostgres},
+            repositories::{
+                brawlers::BrawlerPostgres, friendships::FriendshipPostgres, outbox::OutboxPostgres,
+            },
         },
+        http::client_errors::client_error,
         http::middlewares::auth::authorization,
     },
 };
@@ -20,10 +23,11 @@ use std::sync::Arc;
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let friendship_repository = FriendshipPostgres::new(Arc::clone(&db_pool));
-    let brawler_repository = BrawlerPostgres::new(db_pool);
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
     let friendship_use_case = FriendshipUseCase::new(
         Arc::new(friendship_repository),
         Arc::new(brawler_repository),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
     );
 
     Router::new()
@@ -40,12 +44,14 @@ pub struct SearchQuery {
 }
 
 pub async fn search_friend(
-    State(friendship_use_case): State<Arc<FriendshipUseCase<FriendshipPostgres, BrawlerPostgres>>>,
+    State(friendship_use_case): State<
+        Arc<FriendshipUseCase<FriendshipPostgres, BrawlerPostgres, OutboxPostgres>>,
+    >,
     Query(search_query): Query<SearchQuery>,
 ) -> impl IntoResponse {
     match friendship_use_case.search_friend(search_query.query).await {
         Ok(friend) => (StatusCode::OK, Json(BrawlerSummaryModel::from(friend))).into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
@@ -55,7 +61,9 @@ pub struct AddFriendRequest {
 }
 
 pub async fn add_friend(
-    State(friendship_use_case): State<Arc<FriendshipUseCase<FriendshipPostgres, BrawlerPostgres>>>,
+    State(friendship_use_case): State<
+        Arc<FriendshipUseCase<FriendshipPostgres, BrawlerPostgres, OutboxPostgres>>,
+    >,
     Extension(brawler_id): Extension<i32>,
     Json(add_friend_request): Json<AddFriendRequest>,
 ) -> impl IntoResponse {
@@ -64,12 +72,14 @@ pub async fn add_friend(
         .await
     {
         Ok(_) => (StatusCode::OK, "Friend added successfully").into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
 pub async fn get_friends(
-    State(friendship_use_case): State<Arc<FriendshipUseCase<FriendshipPostgres, BrawlerPostgres>>>,
+    State(friendship_use_case): State<
+        Arc<FriendshipUseCase<FriendshipPostgres, BrawlerPostgres, OutboxPostgres>>,
+    >,
     Extension(brawler_id): Extension<i32>,
 ) -> impl IntoResponse {
     match friendship_use_case.get_friends(brawler_id).await {
@@ -78,6 +88,6 @@ pub async fn get_friends(
                 friends.into_iter().map(BrawlerSummaryModel::from).collect();
             (StatusCode::OK, Json(friends_summary)).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
+use tracing::warn;
+
+use crate::{
+    domain::value_object::health_model::HealthCheckStatus, infrastructure::health::ReadinessProbe,
+};
+
+#[derive(Clone)]
+pub struct HealthState {
+    probe: Arc<ReadinessProbe>,
+    expose_errors: bool,
+}
+
+// Liveness only says the process is serving requests; dependencies are left to readiness so a
+// database outage takes instances out of rotation instead of getting them restarted
+pub async fn live() -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "status": "alive" })),
+    )
+        .into_response()
+}
+
+pub async fn ready(State(state): State<HealthState>) -> impl IntoResponse {
+    let mut report = state.probe.check().await;
+
+    for check in &mut report.checks {
+        if check.status == HealthCheckStatus::Failed {
+            warn!(
+                "Readiness check {} failed: {}",
+                check.name,
+                check.error.as_deref().unwrap_or_default()
+            );
+            if !state.expose_errors {
+                check.error = None;
+            }
+        }
+    }
+
+    let status_code = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status_code, Json(report)).into_response()
+}
+
+pub fn routes(probe: Arc<ReadinessProbe>, expose_errors: bool) -> Router {
+    Router::new()
+        .route("/live", get(live))
+        .route("/ready", get(ready))
+        .with_state(HealthState {
+            probe,
+            expose_errors,
+        })
+}
@@ -1,8 +1,13 @@
 use crate::{
     application::use_cases::messages::MessageUseCase,
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::messages::MessagePostgres},
-        http::middlewares::auth::authorization,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{messages::MessagePostgres, outbox::OutboxPostgres},
+        },
+        http::client_errors::client_error,
+        http::middlewares::{auth::authorization, rate_limit::rate_limit},
+        rate_limit::{RateLimitGroup, RateLimiter},
     },
 };
 use axum::{
@@ -15,13 +20,23 @@ use axum::{
 use serde::Deserialize;
 use std::sync::Arc;
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let message_repository = MessagePostgres::new(db_pool);
-    let message_use_case = MessageUseCase::new(Arc::new(message_repository));
+pub fn routes(db_pool: Arc<PgPoolSquad>, rate_limiter: Arc<RateLimiter>) -> Router {
+    let message_repository = MessagePostgres::new(Arc::clone(&db_pool));
+    let message_use_case = MessageUseCase::new(
+        Arc::new(message_repository),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+    );
 
     Router::new()
         .route("/messages/{friend_id}", get(get_conversation))
-        .route("/messages/send", post(send_message))
+        // Inside the auth layer, so the budget is per sending brawler
+        .route(
+            "/messages/send",
+            post(send_message).layer(axum::middleware::from_fn_with_state(
+                (rate_limiter, RateLimitGroup::Messages),
+                rate_limit,
+            )),
+        )
         .layer(axum::middleware::from_fn(authorization))
         .with_state(Arc::new(message_use_case))
 }
@@ -33,7 +48,7 @@ pub struct SendMessageRequest {
 }
 
 pub async fn send_message(
-    State(message_use_case): State<Arc<MessageUseCase<MessagePostgres>>>,
+    State(message_use_case): State<Arc<MessageUseCase<MessagePostgres, OutboxPostgres>>>,
     Extension(brawler_id): Extension<i32>,
     Json(payload): Json<SendMessageRequest>,
 ) -> impl IntoResponse {
@@ -42,12 +57,12 @@ pub async fn send_message(
         .await
     {
         Ok(msg) => (StatusCode::OK, Json(msg)).into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
 pub async fn get_conversation(
-    State(message_use_case): State<Arc<MessageUseCase<MessagePostgres>>>,
+    State(message_use_case): State<Arc<MessageUseCase<MessagePostgres, OutboxPostgres>>>,
     Extension(brawler_id): Extension<i32>,
     Path(friend_id): Path<i32>,
 ) -> impl IntoResponse {
@@ -56,6 +71,6 @@ pub async fn get_conversation(
         .await
     {
         Ok(msgs) => (StatusCode::OK, Json(msgs)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
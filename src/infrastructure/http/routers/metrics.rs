@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::outbox::OutboxUseCase,
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::outbox::OutboxPostgres},
+        http::client_errors::client_error,
+        metrics::Metrics,
+        realtime::notification_hub::NotificationHub,
+    },
+};
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Clone)]
+pub struct MetricsState {
+    metrics: Arc<Metrics>,
+    db_pool: Arc<PgPoolSquad>,
+    notification_hub: Arc<NotificationHub>,
+    outbox_use_case: Arc<OutboxUseCase<OutboxPostgres>>,
+    token: Option<String>,
+}
+
+fn authorized(state: &MetricsState, headers: &HeaderMap) -> bool {
+    let Some(token) = &state.token else {
+        return true;
+    };
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    bearer == Some(token.as_str())
+}
+
+pub async fn scrape(State(state): State<MetricsState>, headers: HeaderMap) -> impl IntoResponse {
+    if !authorized(&state, &headers) {
+        return (StatusCode::UNAUTHORIZED, "Invalid metrics token").into_response();
+    }
+
+    match state
+        .metrics
+        .render(&state.db_pool, &state.notification_hub)
+    {
+        Ok(body) => ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], body).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+// Operator view of the outbox relay, so it sits with /metrics rather than on the brawler API
+pub async fn outbox_lag(
+    State(state): State<MetricsState>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    if !authorized(&state, &headers) {
+        return (StatusCode::UNAUTHORIZED, "Invalid metrics token").into_response();
+    }
+
+    match state.outbox_use_case.get_lag().await {
+        Ok(lag) => (StatusCode::OK, Json(lag)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    notification_hub: Arc<NotificationHub>,
+    metrics: Arc<Metrics>,
+    token: Option<String>,
+) -> Router {
+    let outbox_use_case = Arc::new(OutboxUseCase::new(Arc::new(OutboxPostgres::new(
+        Arc::clone(&db_pool),
+    ))));
+
+    Router::new()
+        .route("/metrics", get(scrape))
+        .route("/outbox/lag", get(outbox_lag))
+        .with_state(MetricsState {
+            metrics,
+            db_pool,
+            notification_hub,
+            outbox_use_case,
+            token,
+        })
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_comments::MissionCommentUseCase,
+    domain::{
+        repositories::{
+            mission_comments::MissionCommentRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_object::{
+            mission_comment_model::{AddMissionCommentModel, EditMissionCommentModel},
+            pagination::Pagination,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_comments::MissionCommentPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+
+pub async fn get_thread<T1, T2>(
+    State(mission_comment_use_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    pagination: Query<Pagination>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_comment_use_case
+        .get_thread(mission_id, brawler_id, &pagination)
+        .await
+    {
+        Ok(comments) => (StatusCode::OK, Json(comments)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(mission_comment_use_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionCommentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_comment_use_case
+        .add(mission_id, brawler_id, model)
+        .await
+    {
+        Ok(comment_id) => (StatusCode::CREATED, comment_id.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(mission_comment_use_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(comment_id): Path<i32>,
+    Json(model): Json<EditMissionCommentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_comment_use_case
+        .edit(comment_id, brawler_id, model)
+        .await
+    {
+        Ok(comment_id) => (
+            StatusCode::OK,
+            format!("Edit comment: {} completed!!", comment_id),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(mission_comment_use_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(comment_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_comment_use_case
+        .remove(comment_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove comment_id : {} completed!!", comment_id),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let comment_repository = MissionCommentPostgres::new(Arc::clone(&db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let use_case =
+        MissionCommentUseCase::new(Arc::new(comment_repository), Arc::new(viewing_repository));
+
+    Router::new()
+        .route(
+            "/{mission_id}",
+            get(get_thread::<MissionCommentPostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/{mission_id}/create",
+            post(add::<MissionCommentPostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/edit/{comment_id}",
+            patch(edit::<MissionCommentPostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/remove/{comment_id}",
+            delete(remove::<MissionCommentPostgres, MissionViewingPostgres>),
+        )
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::result::Error as DieselError;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::domain::repositories::{
+        mission_comments::MockMissionCommentRepository,
+        mission_viewing::MockMissionViewingRepository,
+    };
+
+    async fn serve(viewing_repository: MockMissionViewingRepository) -> String {
+        let use_case = MissionCommentUseCase::new(
+            Arc::new(MockMissionCommentRepository::new()),
+            Arc::new(viewing_repository),
+        );
+        let app = Router::new()
+            .route(
+                "/{mission_id}/create",
+                post(add::<MockMissionCommentRepository, MockMissionViewingRepository>),
+            )
+            .layer(Extension(6))
+            .with_state(Arc::new(use_case));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        format!("http://{}/5/create", addr)
+    }
+
+    async fn post_comment(url: &str, content: &str) -> (reqwest::StatusCode, String) {
+        let response = reqwest::Client::new()
+            .post(url)
+            .json(&serde_json::json!({ "content": content }))
+            .send()
+            .await
+            .unwrap();
+
+        (response.status(), response.text().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn validation_errors_are_returned_as_400_with_their_message() {
+        let url = serve(MockMissionViewingRepository::new()).await;
+
+        let (status, body) = post_comment(&url, "   ").await;
+
+        assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+        assert_eq!(body, "Comment cannot be empty");
+    }
+
+    #[tokio::test]
+    async fn database_errors_are_returned_as_500() {
+        let mut viewing_repository = MockMissionViewingRepository::new();
+        viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Err(DieselError::BrokenTransactionManager.into()) }));
+        let url = serve(viewing_repository).await;
+
+        let (status, _) = post_comment(&url, "On my way").await;
+
+        assert_eq!(status, reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -14,43 +14,52 @@ use crate::{
     domain::{
         repositories::{
             mission_management::MissionManagementRepository,
-            mission_viewing::MissionViewingRepository,
+            mission_series::MissionSeriesRepository, mission_viewing::MissionViewingRepository,
+            outbox::OutboxRepository, transaction_provider::TransactionProvider,
         },
         value_object::mission_moddel::{AddMissionModel, EditMissionModel},
     },
     infrastructure::{
         database::postgresql_connection::PgPoolSquad,
         database::repositories::{
-            mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres,
+            mission_management::MissionManagementPostgres, mission_series::MissionSeriesPostgres,
+            mission_viewing::MissionViewingPostgres, outbox::OutboxPostgres,
+        },
+        http::{
+            client_errors::client_error, middlewares::auth::authorization,
+            routers::mission_templates,
         },
-        http::middlewares::auth::authorization,
     },
 };
 
-pub async fn add<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn add<T1, T2, T3, T4>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>,
     Json(model): Json<AddMissionModel>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+    T4: MissionSeriesRepository + Send + Sync + 'static,
 {
     match user_case.add(user_id, model).await {
         Ok(mission_id) => (StatusCode::CREATED, mission_id.to_string()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub async fn edit<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn edit<T1, T2, T3, T4>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
     Json(model): Json<EditMissionModel>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+    T4: MissionSeriesRepository + Send + Sync + 'static,
 {
     match user_case.edit(mission_id, user_id, model).await {
         Ok(mission_id) => (
@@ -58,18 +67,20 @@ where
             format!("Edit mission: {} completed!!", mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub async fn remove<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn remove<T1, T2, T3, T4>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+    T4: MissionSeriesRepository + Send + Sync + 'static,
 {
     match user_case.remove(mission_id, user_id).await {
         Ok(_) => (
@@ -77,29 +88,55 @@ where
             format!("Remove mission_id : {} completed!!", mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
     let viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let use_case =
-        MissionManagementUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repository));
+    let use_case = MissionManagementUseCase::new(
+        Arc::new(mission_repository),
+        Arc::new(viewing_repository),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionSeriesPostgres::new(Arc::clone(&db_pool))),
+    );
 
     Router::new()
         .route(
             "/create",
-            post(add::<MissionManagementPostgres, MissionViewingPostgres>),
+            post(
+                add::<
+                    MissionManagementPostgres,
+                    MissionViewingPostgres,
+                    OutboxPostgres,
+                    MissionSeriesPostgres,
+                >,
+            ),
         )
         .route(
             "/{mission_id}/edit",
-            patch(edit::<MissionManagementPostgres, MissionViewingPostgres>),
+            patch(
+                edit::<
+                    MissionManagementPostgres,
+                    MissionViewingPostgres,
+                    OutboxPostgres,
+                    MissionSeriesPostgres,
+                >,
+            ),
         )
         .route(
             "/{mission_id}/remove",
-            delete(remove::<MissionManagementPostgres, MissionViewingPostgres>),
+            delete(
+                remove::<
+                    MissionManagementPostgres,
+                    MissionViewingPostgres,
+                    OutboxPostgres,
+                    MissionSeriesPostgres,
+                >,
+            ),
         )
         .route_layer(middleware::from_fn(authorization))
         .with_state(Arc::new(use_case))
+        .merge(mission_templates::clone_routes(db_pool))
 }
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_objectives::MissionObjectiveUseCase,
+    domain::{
+        repositories::{
+            mission_objectives::MissionObjectiveRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_object::mission_objective_model::{
+            AddMissionObjectiveModel, EditMissionObjectiveModel,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_objectives::MissionObjectivePostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+
+pub async fn get_objectives<T1, T2>(
+    State(mission_objective_use_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_objective_use_case.get_objectives(mission_id).await {
+        Ok(objectives) => (StatusCode::OK, Json(objectives)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(mission_objective_use_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_objective_use_case
+        .add(mission_id, chief_id, model)
+        .await
+    {
+        Ok(objective_id) => (StatusCode::CREATED, objective_id.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(mission_objective_use_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(objective_id): Path<i32>,
+    Json(model): Json<EditMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_objective_use_case
+        .edit(objective_id, chief_id, model)
+        .await
+    {
+        Ok(objective_id) => (
+            StatusCode::OK,
+            format!("Edit objective: {} completed!!", objective_id),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(mission_objective_use_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(objective_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_objective_use_case
+        .remove(objective_id, chief_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove objective_id : {} completed!!", objective_id),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn complete<T1, T2>(
+    State(mission_objective_use_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(objective_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_objective_use_case
+        .complete(objective_id, brawler_id)
+        .await
+    {
+        Ok(objective_id) => (
+            StatusCode::OK,
+            format!(
+                "Objective({}) completed by Brawler({})",
+                objective_id, brawler_id
+            ),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn uncomplete<T1, T2>(
+    State(mission_objective_use_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(objective_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_objective_use_case
+        .uncomplete(objective_id, brawler_id)
+        .await
+    {
+        Ok(objective_id) => (
+            StatusCode::OK,
+            format!("Objective({}) is no longer completed", objective_id),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let objective_repository = MissionObjectivePostgres::new(Arc::clone(&db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let use_case =
+        MissionObjectiveUseCase::new(Arc::new(objective_repository), Arc::new(viewing_repository));
+
+    Router::new()
+        .route(
+            "/{mission_id}",
+            get(get_objectives::<MissionObjectivePostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/{mission_id}/create",
+            post(add::<MissionObjectivePostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/edit/{objective_id}",
+            patch(edit::<MissionObjectivePostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/remove/{objective_id}",
+            delete(remove::<MissionObjectivePostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/complete/{objective_id}",
+            patch(complete::<MissionObjectivePostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/uncomplete/{objective_id}",
+            patch(uncomplete::<MissionObjectivePostgres, MissionViewingPostgres>),
+        )
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
@@ -11,10 +11,13 @@ use axum::{
 
 use crate::{
     application::use_cases::mission_operation::MissionOperationUseCase,
+    config::config_model::AppConfig,
     domain::{
         repositories::{
+            mission_objectives::MissionObjectiveRepository,
             mission_operation::MissionOperationRepository,
-            mission_viewing::MissionViewingRepository,
+            mission_viewing::MissionViewingRepository, outbox::OutboxRepository,
+            transaction_provider::TransactionProvider,
         },
         value_object::mission_statuses::MissionStatuses,
     },
@@ -22,22 +25,28 @@ use crate::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
+                mission_objectives::MissionObjectivePostgres,
                 mission_operation::MissionOperationPostgres,
-                mission_viewing::MissionViewingPostgres,
+                mission_viewing::MissionViewingPostgres, outbox::OutboxPostgres,
             },
         },
+        http::client_errors::client_error,
         http::middlewares::auth::authorization,
     },
 };
 
-pub async fn in_progress<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+type UseCaseState<T1, T2, T3, T4> = State<Arc<MissionOperationUseCase<T1, T2, T3, T4>>>;
+
+pub async fn in_progress<T1, T2, T3, T4>(
+    State(mission_operation_use_case): UseCaseState<T1, T2, T3, T4>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionObjectiveRepository + Send + Sync,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
     match mission_operation_use_case
         .in_progress(mission_id, chief_id)
@@ -52,18 +61,20 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub async fn to_completed<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn to_completed<T1, T2, T3, T4>(
+    State(mission_operation_use_case): UseCaseState<T1, T2, T3, T4>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionObjectiveRepository + Send + Sync,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
     match mission_operation_use_case
         .to_completed(mission_id, chief_id)
@@ -78,18 +89,20 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub async fn to_failed<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn to_failed<T1, T2, T3, T4>(
+    State(mission_operation_use_case): UseCaseState<T1, T2, T3, T4>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionObjectiveRepository + Send + Sync,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
 {
     match mission_operation_use_case
         .to_failed(mission_id, chief_id)
@@ -104,17 +117,21 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<AppConfig>) -> Router {
     // หมายเหตุ: ในสไลด์มีการสะกด Misssion (s 3 ตัว) ให้ตรวจสอบ Class Name ของคุณอีกครั้ง
     let mission_operation_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
     let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let mission_objective_repository = MissionObjectivePostgres::new(Arc::clone(&db_pool));
     let use_case = MissionOperationUseCase::new(
         Arc::new(mission_operation_repository),
         Arc::new(mission_viewing_repository),
+        Arc::new(mission_objective_repository),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+        config,
     );
 
     Router::new()
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::mission_ratings::MissionRatingUseCase,
+    domain::{
+        repositories::{
+            mission_ratings::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_object::mission_rating_model::AddMissionRatingModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_ratings::MissionRatingPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+
+pub async fn rate<T1, T2>(
+    State(mission_rating_use_case): State<Arc<MissionRatingUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionRatingModel>,
+) -> impl IntoResponse
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_rating_use_case
+        .rate(mission_id, brawler_id, model)
+        .await
+    {
+        Ok(rating_id) => (StatusCode::CREATED, rating_id.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_mission_ratings<T1, T2>(
+    State(mission_rating_use_case): State<Arc<MissionRatingUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_rating_use_case
+        .get_mission_ratings(mission_id)
+        .await
+    {
+        Ok(ratings) => (StatusCode::OK, Json(ratings)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_reputation<T1, T2>(
+    State(mission_rating_use_case): State<Arc<MissionRatingUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_rating_use_case.get_reputation(brawler_id).await {
+        Ok(reputation) => (StatusCode::OK, Json(reputation)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let rating_repository = MissionRatingPostgres::new(Arc::clone(&db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let use_case =
+        MissionRatingUseCase::new(Arc::new(rating_repository), Arc::new(viewing_repository));
+
+    let protected_router = Router::new()
+        .route(
+            "/{mission_id}/rate",
+            post(rate::<MissionRatingPostgres, MissionViewingPostgres>),
+        )
+        .route_layer(middleware::from_fn(authorization));
+
+    Router::new()
+        .route(
+            "/{mission_id}",
+            get(get_mission_ratings::<MissionRatingPostgres, MissionViewingPostgres>),
+        )
+        .route(
+            "/reputation/{brawler_id}",
+            get(get_reputation::<MissionRatingPostgres, MissionViewingPostgres>),
+        )
+        .merge(protected_router)
+        .with_state(Arc::new(use_case))
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch},
+};
+
+use crate::{
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    infrastructure::{
+        database::postgresql_connection::PgPoolSquad,
+        database::repositories::{
+            mission_management::MissionManagementPostgres,
+            mission_objectives::MissionObjectivePostgres, mission_series::MissionSeriesPostgres,
+            mission_templates::MissionTemplatePostgres, outbox::OutboxPostgres,
+        },
+        http::middlewares::auth::authorization,
+    },
+};
+
+type SeriesUseCase = MissionSeriesUseCase<
+    MissionSeriesPostgres,
+    MissionTemplatePostgres,
+    MissionManagementPostgres,
+    MissionObjectivePostgres,
+    OutboxPostgres,
+>;
+
+pub async fn get_one(
+    State(use_case): State<Arc<SeriesUseCase>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get(series_id, brawler_id).await {
+        Ok(series) => (StatusCode::OK, Json(series)).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn stop(
+    State(use_case): State<Arc<SeriesUseCase>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.stop(series_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Series {} will not spawn new missions", series_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let use_case = MissionSeriesUseCase::new(
+        Arc::new(MissionSeriesPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionObjectivePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    Router::new()
+        .route("/{series_id}", get(get_one))
+        .route("/{series_id}/stop", patch(stop))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::mission_templates::MissionTemplateUseCase,
+    domain::{
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_objectives::MissionObjectiveRepository,
+            mission_templates::MissionTemplateRepository, outbox::OutboxRepository,
+            transaction_provider::TransactionProvider,
+        },
+        value_object::mission_template_model::{InstantiateMissionModel, SaveMissionTemplateModel},
+    },
+    infrastructure::{
+        database::postgresql_connection::PgPoolSquad,
+        database::repositories::{
+            mission_management::MissionManagementPostgres,
+            mission_objectives::MissionObjectivePostgres,
+            mission_templates::MissionTemplatePostgres, outbox::OutboxPostgres,
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+
+type TemplateUseCase = MissionTemplateUseCase<
+    MissionTemplatePostgres,
+    MissionManagementPostgres,
+    MissionObjectivePostgres,
+    OutboxPostgres,
+>;
+
+pub async fn save_from_mission<T1, T2, T3, T4>(
+    State(use_case): State<Arc<MissionTemplateUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<SaveMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    match use_case
+        .save_from_mission(brawler_id, mission_id, model)
+        .await
+    {
+        Ok(template_id) => (StatusCode::CREATED, template_id.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn list<T1, T2, T3, T4>(
+    State(use_case): State<Arc<MissionTemplateUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    match use_case.list(brawler_id).await {
+        Ok(templates) => (StatusCode::OK, Json(templates)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_one<T1, T2, T3, T4>(
+    State(use_case): State<Arc<MissionTemplateUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    match use_case.get(brawler_id, template_id).await {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2, T3, T4>(
+    State(use_case): State<Arc<MissionTemplateUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    match use_case.remove(brawler_id, template_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
+pub async fn create_mission<T1, T2, T3, T4>(
+    State(use_case): State<Arc<MissionTemplateUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    Json(model): Json<InstantiateMissionModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    match use_case
+        .create_mission(brawler_id, template_id, model)
+        .await
+    {
+        Ok(mission_id) => (StatusCode::CREATED, mission_id.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn clone_mission<T1, T2, T3, T4>(
+    State(use_case): State<Arc<MissionTemplateUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<InstantiateMissionModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync + 'static,
+    T3: MissionObjectiveRepository + Send + Sync + 'static,
+    T4: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    match use_case.clone_mission(brawler_id, mission_id, model).await {
+        Ok(mission_id) => (StatusCode::CREATED, mission_id.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+fn use_case(db_pool: Arc<PgPoolSquad>) -> TemplateUseCase {
+    MissionTemplateUseCase::new(
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionObjectivePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(OutboxPostgres::new(Arc::clone(&db_pool))),
+    )
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(list::<
+                MissionTemplatePostgres,
+                MissionManagementPostgres,
+                MissionObjectivePostgres,
+                OutboxPostgres,
+            >),
+        )
+        .route(
+            "/{template_id}",
+            get(get_one::<
+                MissionTemplatePostgres,
+                MissionManagementPostgres,
+                MissionObjectivePostgres,
+                OutboxPostgres,
+            >)
+            .delete(
+                remove::<
+                    MissionTemplatePostgres,
+                    MissionManagementPostgres,
+                    MissionObjectivePostgres,
+                    OutboxPostgres,
+                >,
+            ),
+        )
+        .route(
+            "/from-mission/{mission_id}",
+            post(
+                save_from_mission::<
+                    MissionTemplatePostgres,
+                    MissionManagementPostgres,
+                    MissionObjectivePostgres,
+                    OutboxPostgres,
+                >,
+            ),
+        )
+        .route(
+            "/{template_id}/create",
+            post(
+                create_mission::<
+                    MissionTemplatePostgres,
+                    MissionManagementPostgres,
+                    MissionObjectivePostgres,
+                    OutboxPostgres,
+                >,
+            ),
+        )
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case(db_pool)))
+}
+
+// Mounted under /missions next to the other mission management actions
+pub fn clone_routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    Router::new()
+        .route(
+            "/{mission_id}/clone",
+            post(
+                clone_mission::<
+                    MissionTemplatePostgres,
+                    MissionManagementPostgres,
+                    MissionObjectivePostgres,
+                    OutboxPostgres,
+                >,
+            ),
+        )
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case(db_pool)))
+}
@@ -1,32 +1,46 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderName, StatusCode},
+    middleware,
     response::IntoResponse,
     routing::get,
 };
 
 use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
+    config::config_model::AppConfig,
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
-        value_object::mission_filter::MissionFilter,
+        value_object::{mission_filter::MissionFilter, pagination::Pagination},
     },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_viewing::MissionViewingPostgres,
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::optional_authorization,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub const X_TOTAL_COUNT: HeaderName = HeaderName::from_static("x-total-count");
+pub const X_PAGE: HeaderName = HeaderName::from_static("x-page");
+pub const X_PAGE_SIZE: HeaderName = HeaderName::from_static("x-page-size");
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, config: Arc<AppConfig>) -> Router {
     let viewing_repository = MissionViewingPostgres::new(db_pool);
-    let use_case = MissionViewingUseCase::new(Arc::new(viewing_repository));
+    let use_case = MissionViewingUseCase::new(Arc::new(viewing_repository), config);
 
     Router::new()
         .route("/{mission_id}", get(get_one))
         .route("/{mission_id}/crew", get(get_crew))
-        .route("/filter", get(get_all))
+        .route(
+            "/filter",
+            get(get_all).layer(middleware::from_fn(optional_authorization)),
+        )
         .route("/crew/{mission_id}", get(get_crew))
         .with_state(Arc::new(use_case))
 }
@@ -40,20 +54,35 @@ where
 {
     match mission_viewing_use_case.get_one(mission_id).await {
         Ok(mission_model) => (StatusCode::OK, Json(mission_model)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
 pub async fn get_all<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Extension(viewer_id): Extension<Option<i32>>,
     filter: Query<MissionFilter>,
+    pagination: Query<Pagination>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match mission_viewing_use_case.get_all(&filter).await {
-        Ok(mission_models) => (StatusCode::OK, Json(mission_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    match mission_viewing_use_case
+        .get_all(&filter, viewer_id, &pagination)
+        .await
+    {
+        // The body stays the plain array existing clients read; paging travels in headers
+        Ok(page) => (
+            StatusCode::OK,
+            [
+                (X_TOTAL_COUNT, page.total.to_string()),
+                (X_PAGE, page.page.to_string()),
+                (X_PAGE_SIZE, page.page_size.to_string()),
+            ],
+            Json(page.items),
+        )
+            .into_response(),
+        Err(e) => client_error(&e),
     }
 }
 
@@ -66,6 +95,6 @@ where
 {
     match mission_viewing_use_case.get_crew(mission_id).await {
         Ok(brawler_models) => (StatusCode::OK, Json(brawler_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => client_error(&e),
     }
 }
@@ -1,9 +1,22 @@
 pub mod authentication;
 pub mod brawlers;
+pub mod calendar;
+pub mod channels;
 pub mod crew_operation;
 pub mod default_routers;
 pub mod friendships;
+pub mod health;
 pub mod messages;
+pub mod metrics;
+pub mod mission_comments;
 pub mod mission_management;
+pub mod mission_objectives;
 pub mod mission_operation;
+pub mod mission_ratings;
+pub mod mission_series;
+pub mod mission_templates;
 pub mod mission_viewing;
+pub mod notifications;
+pub mod progression;
+pub mod tags;
+pub mod webhooks;
@@ -0,0 +1,132 @@
+use std::{convert::Infallible, sync::Arc};
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::{get, patch},
+};
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
+
+use crate::{
+    application::use_cases::notifications::NotificationUseCase,
+    domain::{
+        repositories::notifications::NotificationRepository, value_object::pagination::Pagination,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::notifications::NotificationPostgres,
+        },
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+        realtime::notification_hub::NotificationHub,
+    },
+};
+
+pub async fn get_notifications<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    pagination: Query<Pagination>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case
+        .get_notifications(brawler_id, &pagination)
+        .await
+    {
+        Ok(notifications) => (StatusCode::OK, Json(notifications)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_unread_count<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case.get_unread_count(brawler_id).await {
+        Ok(unread_count) => (StatusCode::OK, Json(unread_count)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn mark_read<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(notification_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case
+        .mark_read(notification_id, brawler_id)
+        .await
+    {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn mark_all_read<T>(
+    State(notification_use_case): State<Arc<NotificationUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationRepository + Send + Sync,
+{
+    match notification_use_case.mark_all_read(brawler_id).await {
+        Ok(updated) => (StatusCode::OK, updated.to_string()).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn stream(
+    State(notification_hub): State<Arc<NotificationHub>>,
+    Extension(brawler_id): Extension<i32>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    // Lagged receivers skip the dropped items; clients can re-sync through the list endpoint
+    let notifications = BroadcastStream::new(notification_hub.subscribe(brawler_id))
+        .filter_map(|notification| notification.ok())
+        .filter_map(|notification| {
+            Event::default()
+                .event("notification")
+                .json_data(notification)
+                .ok()
+        })
+        .map(Ok);
+
+    Sse::new(notifications).keep_alive(KeepAlive::default())
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, notification_hub: Arc<NotificationHub>) -> Router {
+    let notification_repository =
+        NotificationPostgres::new(Arc::clone(&db_pool), Arc::clone(&notification_hub));
+    let use_case = NotificationUseCase::new(Arc::new(notification_repository));
+
+    let stream_router = Router::new()
+        .route("/stream", get(stream))
+        .with_state(notification_hub);
+
+    Router::new()
+        .route("/", get(get_notifications::<NotificationPostgres>))
+        .route(
+            "/unread-count",
+            get(get_unread_count::<NotificationPostgres>),
+        )
+        .route("/read-all", patch(mark_all_read::<NotificationPostgres>))
+        .route(
+            "/read/{notification_id}",
+            patch(mark_read::<NotificationPostgres>),
+        )
+        .with_state(Arc::new(use_case))
+        .merge(stream_router)
+        .route_layer(middleware::from_fn(authorization))
+}
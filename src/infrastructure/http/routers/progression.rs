@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::progression::ProgressionUseCase,
+    domain::repositories::progression::ProgressionRepository,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::progression::ProgressionPostgres,
+        },
+        http::client_errors::client_error,
+    },
+};
+
+pub async fn get_catalog<T>(
+    State(progression_use_case): State<Arc<ProgressionUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    (StatusCode::OK, Json(progression_use_case.get_catalog())).into_response()
+}
+
+pub async fn get_progress<T>(
+    State(progression_use_case): State<Arc<ProgressionUseCase<T>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ProgressionRepository + Send + Sync,
+{
+    match progression_use_case.get_progress(brawler_id).await {
+        Ok(progress) => (StatusCode::OK, Json(progress)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let progression_repository = ProgressionPostgres::new(Arc::clone(&db_pool));
+    let use_case = ProgressionUseCase::new(Arc::new(progression_repository));
+
+    Router::new()
+        .route("/achievements", get(get_catalog::<ProgressionPostgres>))
+        .route("/{brawler_id}", get(get_progress::<ProgressionPostgres>))
+        .with_state(Arc::new(use_case))
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::tags::TagUseCase,
+    domain::{repositories::tags::TagRepository, value_object::tag_model::TagSuggestionFilter},
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::tags::TagPostgres},
+        http::client_errors::client_error,
+    },
+};
+
+pub async fn suggest<T>(
+    State(tag_use_case): State<Arc<TagUseCase<T>>>,
+    Query(filter): Query<TagSuggestionFilter>,
+) -> impl IntoResponse
+where
+    T: TagRepository + Send + Sync,
+{
+    match tag_use_case.suggest(&filter).await {
+        Ok(tags) => (StatusCode::OK, Json(tags)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let tag_repository = TagPostgres::new(Arc::clone(&db_pool));
+    let use_case = TagUseCase::new(Arc::new(tag_repository));
+
+    Router::new()
+        .route("/suggest", get(suggest::<TagPostgres>))
+        .with_state(Arc::new(use_case))
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    application::use_cases::webhooks::WebhookUseCase,
+    domain::{
+        repositories::webhooks::WebhookRepository,
+        value_object::{pagination::Pagination, webhook_model::AddWebhookModel},
+    },
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::webhooks::WebhookPostgres},
+        http::client_errors::client_error,
+        http::middlewares::auth::authorization,
+    },
+};
+
+pub async fn register<T>(
+    State(webhook_use_case): State<Arc<WebhookUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(model): Json<AddWebhookModel>,
+) -> impl IntoResponse
+where
+    T: WebhookRepository + Send + Sync,
+{
+    match webhook_use_case.register(brawler_id, model).await {
+        Ok(webhook) => (StatusCode::CREATED, Json(webhook)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_webhooks<T>(
+    State(webhook_use_case): State<Arc<WebhookUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: WebhookRepository + Send + Sync,
+{
+    match webhook_use_case.get_webhooks(brawler_id).await {
+        Ok(webhooks) => (StatusCode::OK, Json(webhooks)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn remove<T>(
+    State(webhook_use_case): State<Arc<WebhookUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: WebhookRepository + Send + Sync,
+{
+    match webhook_use_case.remove(webhook_id, brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub async fn get_deliveries<T>(
+    State(webhook_use_case): State<Arc<WebhookUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+    pagination: Query<Pagination>,
+) -> impl IntoResponse
+where
+    T: WebhookRepository + Send + Sync,
+{
+    match webhook_use_case
+        .get_deliveries(webhook_id, brawler_id, &pagination)
+        .await
+    {
+        Ok(deliveries) => (StatusCode::OK, Json(deliveries)).into_response(),
+        Err(e) => client_error(&e),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let webhook_repository = WebhookPostgres::new(Arc::clone(&db_pool));
+    let use_case = WebhookUseCase::new(Arc::new(webhook_repository));
+
+    Router::new()
+        .route("/", get(get_webhooks::<WebhookPostgres>))
+        .route("/create", post(register::<WebhookPostgres>))
+        .route("/remove/{webhook_id}", delete(remove::<WebhookPostgres>))
+        .route(
+            "/{webhook_id}/deliveries",
+            get(get_deliveries::<WebhookPostgres>),
+        )
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::new(use_case))
+}
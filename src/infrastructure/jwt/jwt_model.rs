@@ -2,7 +2,7 @@ use anyhow::Result;
 use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::{config::config_loader::get_jwt_env, infrastructure::jwt::generate_token};
+use crate::{config::config_model::JwtEnv, infrastructure::jwt::generate_token};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
@@ -28,8 +28,8 @@ impl Passport {
         display_name: String,
         tag: String,
         avatar_url: Option<String>,
+        jwt_env: &JwtEnv,
     ) -> Result<Self> {
-        let jwt_env = get_jwt_env()?;
         let token_type = "Bearer".to_string();
         let expires_in = (Utc::now() + Duration::days(jwt_env.lift_time_days)).timestamp() as usize;
 
@@ -38,7 +38,7 @@ impl Passport {
             exp: expires_in,
             iat: Utc::now().timestamp() as usize,
         };
-        let access_token = generate_token(jwt_env.secret, &access_token_claims)?;
+        let access_token = generate_token(jwt_env.secret.clone(), &access_token_claims)?;
 
         Ok(Self {
             brawler_id,
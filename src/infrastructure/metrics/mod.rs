@@ -0,0 +1,104 @@
+use anyhow::Result;
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+
+use crate::infrastructure::{
+    database::postgresql_connection::PgPoolSquad, realtime::notification_hub::NotificationHub,
+};
+
+// Seconds; the top buckets are there to show requests waiting on the pool or a slow upstream
+const HTTP_LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+// Every metric is registered under the `crate_` prefix in its own registry, so the endpoint only
+// exposes what the server records
+pub struct Metrics {
+    registry: Registry,
+    pub http_requests: IntCounterVec,
+    pub http_request_duration: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    db_pool_max_connections: IntGauge,
+    realtime_connections: IntGauge,
+    pub missions_created: IntCounter,
+    pub crew_joins: IntCounter,
+    pub missions_completed: IntCounter,
+    pub messages_sent: IntCounter,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new_custom(Some("crate".to_string()), None)?;
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled"),
+            &["method", "route", "status"],
+        )?;
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency")
+                .buckets(HTTP_LATENCY_BUCKETS.to_vec()),
+            &["method", "route", "status"],
+        )?;
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Database pool connections by state"),
+            &["state"],
+        )?;
+        let db_pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Largest number of connections the database pool may open",
+        )?;
+        let realtime_connections =
+            IntGauge::new("realtime_connections", "Open notification streams")?;
+        let missions_created = IntCounter::new("missions_created_total", "Missions created")?;
+        let crew_joins = IntCounter::new("crew_joins_total", "Brawlers joining a mission crew")?;
+        let missions_completed =
+            IntCounter::new("missions_completed_total", "Missions moved to Completed")?;
+        let messages_sent = IntCounter::new("messages_sent_total", "Direct messages sent")?;
+
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(http_request_duration.clone()))?;
+        registry.register(Box::new(db_pool_connections.clone()))?;
+        registry.register(Box::new(db_pool_max_connections.clone()))?;
+        registry.register(Box::new(realtime_connections.clone()))?;
+        registry.register(Box::new(missions_created.clone()))?;
+        registry.register(Box::new(crew_joins.clone()))?;
+        registry.register(Box::new(missions_completed.clone()))?;
+        registry.register(Box::new(messages_sent.clone()))?;
+
+        Ok(Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            db_pool_max_connections,
+            realtime_connections,
+            missions_created,
+            crew_joins,
+            missions_completed,
+            messages_sent,
+        })
+    }
+
+    // Pool and stream gauges are sampled at scrape time instead of being tracked on every change
+    pub fn render(
+        &self,
+        db_pool: &PgPoolSquad,
+        notification_hub: &NotificationHub,
+    ) -> Result<String> {
+        let state = db_pool.state();
+        self.db_pool_connections
+            .with_label_values(&["idle"])
+            .set(i64::from(state.idle_connections));
+        self.db_pool_connections
+            .with_label_values(&["in_use"])
+            .set(i64::from(state.connections - state.idle_connections));
+        self.db_pool_max_connections
+            .set(i64::from(db_pool.max_size()));
+        self.realtime_connections
+            .set(i64::try_from(notification_hub.connection_count())?);
+
+        Ok(TextEncoder::new().encode_to_string(&self.registry.gather())?)
+    }
+}
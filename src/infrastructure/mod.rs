@@ -1,5 +1,14 @@
 pub mod argon2;
+pub mod calendar;
 pub mod cloudinary;
 pub mod database;
+pub mod event_bus;
+pub mod health;
 pub mod http;
 pub mod jwt;
+pub mod metrics;
+pub mod rate_limit;
+pub mod realtime;
+pub mod scheduler;
+pub mod telemetry;
+pub mod webhooks;
@@ -0,0 +1,155 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+
+use crate::domain::{
+    repositories::rate_limits::RateLimitRepository,
+    value_object::rate_limit_model::{RateLimitDecision, RateLimitRule},
+};
+
+// Buckets that have refilled completely are dropped once every this many takes, so the sweep
+// does not run under the lock on every request
+const PRUNE_EVERY_TAKES: u64 = 1_000;
+// Hard cap on tracked keys. While it is reached, clients without a bucket are let through
+// untracked until the next prune frees room: turning them away would let one client spraying
+// keys lock out every new user, and the sprayer is not held back by per-key buckets anyway
+const MAX_KEYS: usize = 100_000;
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+    rule: RateLimitRule,
+}
+
+impl Bucket {
+    fn refilled(&self, now: Instant) -> f64 {
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        (self.tokens + elapsed * self.rule.refill_per_second()).min(f64::from(self.rule.requests))
+    }
+}
+
+#[derive(Default)]
+struct Buckets {
+    by_key: HashMap<String, Bucket>,
+    takes: u64,
+}
+
+#[derive(Default)]
+pub struct RateLimitMemory {
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimitMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for RateLimitMemory {
+    async fn take(&self, key: String, rule: RateLimitRule) -> Result<RateLimitDecision> {
+        let now = Instant::now();
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Rate limit buckets lock poisoned"))?;
+
+        buckets.takes += 1;
+        if buckets.takes % PRUNE_EVERY_TAKES == 0 {
+            buckets
+                .by_key
+                .retain(|_, bucket| bucket.refilled(now) < f64::from(bucket.rule.requests));
+        }
+
+        if buckets.by_key.len() >= MAX_KEYS && !buckets.by_key.contains_key(&key) {
+            return Ok(RateLimitDecision {
+                allowed: true,
+                remaining: rule.requests.saturating_sub(1),
+                retry_after_seconds: 0,
+            });
+        }
+
+        let bucket = buckets.by_key.entry(key).or_insert(Bucket {
+            tokens: f64::from(rule.requests),
+            updated_at: now,
+            rule,
+        });
+        // A changed rule applies from the next take without resetting the bucket
+        bucket.rule = rule;
+        bucket.tokens = bucket.refilled(now);
+        bucket.updated_at = now;
+
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+
+        Ok(RateLimitDecision {
+            allowed,
+            remaining: bucket.tokens as u32,
+            retry_after_seconds: if allowed {
+                0
+            } else {
+                rule.retry_after_seconds(bucket.tokens)
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULE: RateLimitRule = RateLimitRule {
+        requests: 10,
+        period_seconds: 60,
+    };
+
+    #[tokio::test]
+    async fn new_keys_fail_open_without_a_bucket_once_the_cap_is_reached() {
+        let memory = RateLimitMemory::new();
+        {
+            let mut buckets = memory.buckets.lock().unwrap();
+            for i in 0..MAX_KEYS {
+                buckets.by_key.insert(
+                    i.to_string(),
+                    Bucket {
+                        tokens: 0.0,
+                        updated_at: Instant::now(),
+                        rule: RULE,
+                    },
+                );
+            }
+        }
+
+        let known = memory.take("0".to_string(), RULE).await.unwrap();
+        let unknown = memory.take("new".to_string(), RULE).await.unwrap();
+
+        assert!(!known.allowed);
+        assert!(unknown.allowed);
+        let buckets = memory.buckets.lock().unwrap();
+        assert_eq!(buckets.by_key.len(), MAX_KEYS);
+        assert!(!buckets.by_key.contains_key("new"));
+    }
+
+    #[tokio::test]
+    async fn full_buckets_are_pruned_every_few_takes() {
+        let memory = RateLimitMemory::new();
+        memory.buckets.lock().unwrap().by_key.insert(
+            "idle".to_string(),
+            Bucket {
+                tokens: f64::from(RULE.requests),
+                updated_at: Instant::now(),
+                rule: RULE,
+            },
+        );
+
+        for _ in 0..PRUNE_EVERY_TAKES - 1 {
+            memory.take("busy".to_string(), RULE).await.unwrap();
+        }
+        assert!(memory.buckets.lock().unwrap().by_key.contains_key("idle"));
+
+        memory.take("busy".to_string(), RULE).await.unwrap();
+        assert!(!memory.buckets.lock().unwrap().by_key.contains_key("idle"));
+    }
+}
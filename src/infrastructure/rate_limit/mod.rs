@@ -0,0 +1,82 @@
+pub mod memory;
+
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::{
+    config::config_model::{RateLimitBackend, RateLimits},
+    domain::{
+        repositories::rate_limits::RateLimitRepository,
+        value_object::rate_limit_model::{RateLimitDecision, RateLimitRule},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::rate_limits::RateLimitPostgres,
+    },
+};
+
+use self::memory::RateLimitMemory;
+
+// Each group has its own rule and its own buckets, so a brawler spending the API budget still has
+// the message budget left
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateLimitGroup {
+    Api,
+    Auth,
+    Messages,
+}
+
+impl RateLimitGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitGroup::Api => "api",
+            RateLimitGroup::Auth => "auth",
+            RateLimitGroup::Messages => "messages",
+        }
+    }
+}
+
+pub struct RateLimiter {
+    store: Option<Arc<dyn RateLimitRepository + Send + Sync>>,
+    config: RateLimits,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimits, db_pool: Arc<PgPoolSquad>) -> Self {
+        let store: Option<Arc<dyn RateLimitRepository + Send + Sync>> = match config.backend {
+            RateLimitBackend::Memory => Some(Arc::new(RateLimitMemory::new())),
+            RateLimitBackend::Postgres => Some(Arc::new(RateLimitPostgres::new(db_pool))),
+            RateLimitBackend::Off => None,
+        };
+
+        Self {
+            store,
+            config: config.clone(),
+        }
+    }
+
+    pub fn trust_forwarded_for(&self) -> bool {
+        self.config.trust_forwarded_for
+    }
+
+    fn rule(&self, group: RateLimitGroup) -> Option<RateLimitRule> {
+        match group {
+            RateLimitGroup::Api => self.config.api,
+            RateLimitGroup::Auth => self.config.auth,
+            RateLimitGroup::Messages => self.config.messages,
+        }
+    }
+
+    // `None` when the backend is off or the group is not limited
+    pub async fn take(
+        &self,
+        group: RateLimitGroup,
+        client: &str,
+    ) -> Result<Option<RateLimitDecision>> {
+        let (Some(store), Some(rule)) = (&self.store, self.rule(group)) else {
+            return Ok(None);
+        };
+
+        let key = format!("{}:{}", group.as_str(), client);
+        Ok(Some(store.take(key, rule).await?))
+    }
+}
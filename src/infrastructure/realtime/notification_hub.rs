@@ -0,0 +1,115 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use tokio::sync::broadcast;
+
+use crate::domain::value_object::notification_model::NotificationModel;
+
+const CHANNEL_CAPACITY: usize = 64;
+
+// One broadcast channel per connected brawler, created on first subscription. A channel whose
+// streams have all disconnected is swept on the next subscribe or publish
+#[derive(Default)]
+pub struct NotificationHub {
+    channels: Mutex<HashMap<i32, broadcast::Sender<NotificationModel>>>,
+    closed: AtomicBool,
+}
+
+impl NotificationHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe(&self, brawler_id: i32) -> broadcast::Receiver<NotificationModel> {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+
+        // The sender is dropped right away, so a stream opened during shutdown ends immediately
+        if self.closed.load(Ordering::SeqCst) {
+            return broadcast::channel(1).1;
+        }
+
+        Self::sweep(&mut channels);
+        channels
+            .entry(brawler_id)
+            .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+            .subscribe()
+    }
+
+    // Dropping every sender ends all open streams, which lets the server finish draining
+    pub fn close(&self) {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+
+        self.closed.store(true, Ordering::SeqCst);
+        channels.clear();
+    }
+
+    // Every open stream holds one receiver
+    pub fn connection_count(&self) -> usize {
+        let channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+
+        channels
+            .values()
+            .map(|sender| sender.receiver_count())
+            .sum()
+    }
+
+    pub fn publish(&self, notification: NotificationModel) {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+
+        Self::sweep(&mut channels);
+        if let Some(sender) = channels.get(&notification.recipient_id) {
+            let _ = sender.send(notification);
+        }
+    }
+
+    fn sweep(channels: &mut HashMap<i32, broadcast::Sender<NotificationModel>>) {
+        channels.retain(|_, sender| sender.receiver_count() > 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel_ids(hub: &NotificationHub) -> Vec<i32> {
+        let mut ids: Vec<i32> = hub.channels.lock().unwrap().keys().copied().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn disconnected_channels_are_swept_on_subscribe() {
+        let hub = NotificationHub::new();
+
+        let first = hub.subscribe(1);
+        let _second = hub.subscribe(2);
+        drop(first);
+        let _third = hub.subscribe(3);
+
+        assert_eq!(channel_ids(&hub), vec![2, 3]);
+    }
+
+    #[test]
+    fn disconnected_channels_are_swept_on_publish() {
+        let hub = NotificationHub::new();
+
+        drop(hub.subscribe(1));
+        hub.publish(NotificationModel {
+            id: 1,
+            recipient_id: 2,
+            actor_id: None,
+            mission_id: None,
+            kind: "FriendRequest".to_string(),
+            content: String::new(),
+            read_at: None,
+            created_at: chrono::Utc::now().naive_utc(),
+        });
+
+        assert!(channel_ids(&hub).is_empty());
+    }
+}
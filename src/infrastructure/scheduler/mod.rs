@@ -0,0 +1,64 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::{
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    domain::{
+        constants::MISSION_SCHEDULER_INTERVAL_SECONDS,
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_objectives::MissionObjectiveRepository,
+            mission_series::MissionSeriesRepository, mission_templates::MissionTemplateRepository,
+            outbox::OutboxRepository, transaction_provider::TransactionProvider,
+        },
+    },
+};
+
+// Row locks on each series keep several server instances from spawning the same occurrence
+pub struct MissionScheduler<T1, T2, T3, T4, T5>
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionTemplateRepository + Send + Sync,
+    T3: MissionManagementRepository + Send + Sync,
+    T4: MissionObjectiveRepository + Send + Sync,
+    T5: OutboxRepository + TransactionProvider + Send + Sync,
+{
+    mission_series_use_case: Arc<MissionSeriesUseCase<T1, T2, T3, T4, T5>>,
+}
+
+impl<T1, T2, T3, T4, T5> MissionScheduler<T1, T2, T3, T4, T5>
+where
+    T1: MissionSeriesRepository + Send + Sync + 'static,
+    T2: MissionTemplateRepository + Send + Sync + 'static,
+    T3: MissionManagementRepository + Send + Sync + 'static,
+    T4: MissionObjectiveRepository + Send + Sync + 'static,
+    T5: OutboxRepository + TransactionProvider + Send + Sync + 'static,
+{
+    pub fn new(mission_series_use_case: Arc<MissionSeriesUseCase<T1, T2, T3, T4, T5>>) -> Self {
+        Self {
+            mission_series_use_case,
+        }
+    }
+
+    pub async fn run(self, shutdown: CancellationToken) {
+        info!("Mission scheduler started");
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(MISSION_SCHEDULER_INTERVAL_SECONDS));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown.cancelled() => break,
+            }
+
+            match self.mission_series_use_case.spawn_due().await {
+                Ok(0) => {}
+                Ok(spawned) => info!("Mission scheduler spawned {} missions", spawned),
+                Err(e) => warn!("Mission scheduler failed: {}", e),
+            }
+        }
+        info!("Mission scheduler stopped");
+    }
+}
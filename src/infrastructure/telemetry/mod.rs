@@ -0,0 +1,77 @@
+use anyhow::Result;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
+
+use crate::config::config_model::{LogFormat, Telemetry};
+
+// A calendar feed URL carries its token, the only credential calendar apps can send
+const CALENDAR_FEED_SEGMENT: &str = "/calendar/feed/";
+
+// Keeps the OTLP pipeline alive; `shutdown` flushes the spans still waiting in the batch
+pub struct TelemetryGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl TelemetryGuard {
+    pub fn shutdown(self) {
+        if let Some(tracer_provider) = self.tracer_provider
+            && let Err(e) = tracer_provider.shutdown()
+        {
+            eprintln!("Failed to flush traces: {}", e);
+        }
+    }
+}
+
+// What request logs and spans record instead of the URI: no query string, feed tokens masked
+pub fn loggable_path(path: &str) -> String {
+    match path.split_once(CALENDAR_FEED_SEGMENT) {
+        Some((prefix, _)) => format!("{}{}{{file}}", prefix, CALENDAR_FEED_SEGMENT),
+        None => path.to_string(),
+    }
+}
+
+pub fn init(telemetry: &Telemetry) -> Result<TelemetryGuard> {
+    let fmt_layer = match telemetry.log_format {
+        // Span fields (request_id, brawler_id) are flattened into every line under `span`
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed(),
+        LogFormat::Text => tracing_subscriber::fmt::layer().boxed(),
+    };
+
+    let tracer_provider = match &telemetry.otlp_endpoint {
+        Some(endpoint) => Some(tracer_provider(endpoint, &telemetry.service_name)?),
+        None => None,
+    };
+    let otel_layer = tracer_provider.as_ref().map(|tracer_provider| {
+        tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("server"))
+    });
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_new(&telemetry.log_filter)?)
+        .with(fmt_layer)
+        .with(otel_layer)
+        .try_init()?;
+
+    Ok(TelemetryGuard { tracer_provider })
+}
+
+fn tracer_provider(endpoint: &str, service_name: &str) -> Result<SdkTracerProvider> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint))
+        .build()?;
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(service_name.to_string())
+                .build(),
+        )
+        .build())
+}
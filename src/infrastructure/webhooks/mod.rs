@@ -0,0 +1,351 @@
+pub mod target;
+
+use std::{sync::Arc, time::Duration};
+
+use anyhow::Result;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::domain::{
+    constants::{
+        WEBHOOK_BATCH_SIZE, WEBHOOK_LEASE_SECONDS, WEBHOOK_MAX_ATTEMPTS,
+        WEBHOOK_POLL_INTERVAL_SECONDS, WEBHOOK_RETRY_BASE_SECONDS, WEBHOOK_TIMEOUT_SECONDS,
+    },
+    repositories::webhooks::WebhookRepository,
+    value_object::webhook_model::WebhookDeliveryJobModel,
+};
+
+#[derive(Debug, Serialize)]
+struct WebhookEnvelope<'a> {
+    id: i32,
+    event: &'a str,
+    created_at: chrono::NaiveDateTime,
+    data: &'a serde_json::Value,
+}
+
+// Receivers verify `X-Webhook-Signature` by computing HMAC-SHA256 over "{timestamp}.{body}"
+// with their secret and comparing it to the hex digest after the "sha256=" prefix
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> Result<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+
+    let digest = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    Ok(format!("sha256={}", digest))
+}
+
+fn retry_delay_seconds(attempts: i32) -> i64 {
+    WEBHOOK_RETRY_BASE_SECONDS * 2_i64.pow(attempts.max(0) as u32)
+}
+
+pub struct WebhookDispatcher<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    webhook_repository: Arc<T>,
+    client: reqwest::Client,
+    check_targets: bool,
+}
+
+impl<T> WebhookDispatcher<T>
+where
+    T: WebhookRepository + Send + Sync,
+{
+    pub fn new(webhook_repository: Arc<T>) -> Result<Self> {
+        let client = target::client_builder()
+            .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECONDS))
+            .build()?;
+
+        Ok(Self {
+            webhook_repository,
+            client,
+            check_targets: true,
+        })
+    }
+
+    pub async fn run(self, shutdown: CancellationToken) {
+        info!("Webhook dispatcher started");
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(WEBHOOK_POLL_INTERVAL_SECONDS));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown.cancelled() => break,
+            }
+
+            if let Err(e) = self.dispatch_due().await {
+                warn!("Webhook dispatch failed: {}", e);
+            }
+        }
+        info!("Webhook dispatcher stopped");
+    }
+
+    pub async fn dispatch_due(&self) -> Result<usize> {
+        let jobs = self
+            .webhook_repository
+            .claim_due(WEBHOOK_BATCH_SIZE, WEBHOOK_LEASE_SECONDS)
+            .await?;
+        let claimed = jobs.len();
+
+        // A failed bookkeeping write only costs that delivery; its lease expires and it is retried
+        for job in jobs {
+            let outcome = self.deliver(&job).await;
+            if let Err(e) = self.record(&job, outcome).await {
+                warn!("Failed to record webhook delivery {}: {}", job.id, e);
+            }
+        }
+
+        Ok(claimed)
+    }
+
+    async fn record(
+        &self,
+        job: &WebhookDeliveryJobModel,
+        outcome: Result<i32, (Option<i32>, String)>,
+    ) -> Result<()> {
+        match outcome {
+            Ok(status_code) => {
+                self.webhook_repository
+                    .mark_delivered(job.id, status_code)
+                    .await
+            }
+            Err((status_code, error)) if job.attempts + 1 >= WEBHOOK_MAX_ATTEMPTS => {
+                self.webhook_repository
+                    .mark_failed(job.id, status_code, error)
+                    .await
+            }
+            Err((status_code, error)) => {
+                let next_attempt_at = Utc::now().naive_utc()
+                    + chrono::Duration::seconds(retry_delay_seconds(job.attempts));
+                self.webhook_repository
+                    .mark_retry(job.id, status_code, error, next_attempt_at)
+                    .await
+            }
+        }
+    }
+
+    async fn deliver(&self, job: &WebhookDeliveryJobModel) -> Result<i32, (Option<i32>, String)> {
+        let body = serde_json::to_string(&WebhookEnvelope {
+            id: job.id,
+            event: &job.event,
+            created_at: job.created_at,
+            data: &job.payload,
+        })
+        .map_err(|e| (None, e.to_string()))?;
+
+        if self.check_targets {
+            target::ensure_public_target(&job.url)
+                .await
+                .map_err(|e| (None, e.to_string()))?;
+        }
+
+        let timestamp = Utc::now().timestamp();
+        let signature = sign(&job.secret, timestamp, &body).map_err(|e| (None, e.to_string()))?;
+
+        let response = self
+            .client
+            .post(&job.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-Webhook-Id", job.id.to_string())
+            .header("X-Webhook-Event", job.event.as_str())
+            .header("X-Webhook-Timestamp", timestamp.to_string())
+            .header("X-Webhook-Signature", signature)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| (None, e.to_string()))?;
+
+        let status_code = i32::from(response.status().as_u16());
+        if !response.status().is_success() {
+            return Err((
+                Some(status_code),
+                format!("Receiver responded with status {}", status_code),
+            ));
+        }
+
+        Ok(status_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{pin::Pin, sync::Mutex};
+
+    use axum::{Router, http::HeaderMap, http::StatusCode, routing::post};
+    use chrono::NaiveDateTime;
+    use mockall::predicate::eq;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::domain::repositories::webhooks::MockWebhookRepository;
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    // Answers every POST with `status` and keeps what it received
+    async fn stub_receiver(status: StatusCode) -> (String, Received) {
+        let received: Received = Arc::default();
+        let app = Router::new().route(
+            "/hook",
+            post({
+                let received = Arc::clone(&received);
+                move |headers: HeaderMap, body: String| async move {
+                    received.lock().unwrap().push((headers, body));
+                    status
+                }
+            }),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (format!("http://{}/hook", addr), received)
+    }
+
+    // The mocks come from #[automock] under #[async_trait], so they return boxed futures
+    fn ready<R: Send + 'static>(
+        result: Result<R>,
+    ) -> Pin<Box<dyn Future<Output = Result<R>> + Send>> {
+        Box::pin(async move { result })
+    }
+
+    fn job(id: i32, url: &str, attempts: i32) -> WebhookDeliveryJobModel {
+        WebhookDeliveryJobModel {
+            id,
+            webhook_id: 1,
+            url: url.to_string(),
+            secret: "whsec_test".to_string(),
+            event: "mission.created".to_string(),
+            payload: serde_json::json!({ "mission_id": 7 }),
+            attempts,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    // The stub listens on loopback, which the production client refuses
+    fn dispatcher(repository: MockWebhookRepository) -> WebhookDispatcher<MockWebhookRepository> {
+        WebhookDispatcher {
+            webhook_repository: Arc::new(repository),
+            client: reqwest::Client::new(),
+            check_targets: false,
+        }
+    }
+
+    fn header<'a>(headers: &'a HeaderMap, name: &str) -> &'a str {
+        headers.get(name).unwrap().to_str().unwrap()
+    }
+
+    #[tokio::test]
+    async fn delivers_a_signed_request() {
+        let (url, received) = stub_receiver(StatusCode::OK).await;
+        let mut repository = MockWebhookRepository::new();
+        let jobs = vec![job(11, &url, 0)];
+        repository
+            .expect_claim_due()
+            .return_once(move |_, _| ready(Ok(jobs)));
+        repository
+            .expect_mark_delivered()
+            .with(eq(11), eq(200))
+            .times(1)
+            .returning(|_, _| ready(Ok(())));
+
+        assert_eq!(dispatcher(repository).dispatch_due().await.unwrap(), 1);
+
+        let received = received.lock().unwrap();
+        let (headers, body) = &received[0];
+        let timestamp = header(headers, "x-webhook-timestamp")
+            .parse::<i64>()
+            .unwrap();
+        assert_eq!(
+            header(headers, "x-webhook-signature"),
+            sign("whsec_test", timestamp, body).unwrap()
+        );
+        assert_eq!(header(headers, "x-webhook-event"), "mission.created");
+        assert_eq!(header(headers, "x-webhook-id"), "11");
+    }
+
+    #[tokio::test]
+    async fn schedules_a_retry_with_backoff() {
+        let (url, _) = stub_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let mut repository = MockWebhookRepository::new();
+        let jobs = vec![job(12, &url, 2)];
+        repository
+            .expect_claim_due()
+            .return_once(move |_, _| ready(Ok(jobs)));
+        let next_attempt = Arc::new(Mutex::new(None::<NaiveDateTime>));
+        repository
+            .expect_mark_retry()
+            .withf(|id, status_code, _, _| *id == 12 && *status_code == Some(500))
+            .times(1)
+            .returning({
+                let next_attempt = Arc::clone(&next_attempt);
+                move |_, _, _, next_attempt_at| {
+                    *next_attempt.lock().unwrap() = Some(next_attempt_at);
+                    ready(Ok(()))
+                }
+            });
+
+        let before = Utc::now().naive_utc();
+        dispatcher(repository).dispatch_due().await.unwrap();
+
+        // Third attempt: base * 2^2
+        let delay = (next_attempt.lock().unwrap().unwrap() - before).num_seconds();
+        let expected = WEBHOOK_RETRY_BASE_SECONDS * 4;
+        assert!(
+            (expected..=expected + 1).contains(&delay),
+            "delay {}",
+            delay
+        );
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_the_last_attempt() {
+        let (url, _) = stub_receiver(StatusCode::BAD_GATEWAY).await;
+        let mut repository = MockWebhookRepository::new();
+        let jobs = vec![job(13, &url, WEBHOOK_MAX_ATTEMPTS - 1)];
+        repository
+            .expect_claim_due()
+            .return_once(move |_, _| ready(Ok(jobs)));
+        repository
+            .expect_mark_failed()
+            .withf(|id, status_code, _| *id == 13 && *status_code == Some(502))
+            .times(1)
+            .returning(|_, _, _| ready(Ok(())));
+        repository.expect_mark_retry().never();
+
+        dispatcher(repository).dispatch_due().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn keeps_going_when_recording_a_delivery_fails() {
+        let (url, received) = stub_receiver(StatusCode::OK).await;
+        let mut repository = MockWebhookRepository::new();
+        let jobs = vec![job(14, &url, 0), job(15, &url, 0)];
+        repository
+            .expect_claim_due()
+            .return_once(move |_, _| ready(Ok(jobs)));
+        repository
+            .expect_mark_delivered()
+            .with(eq(14), eq(200))
+            .times(1)
+            .returning(|_, _| ready(Err(anyhow::anyhow!("connection reset"))));
+        repository
+            .expect_mark_delivered()
+            .with(eq(15), eq(200))
+            .times(1)
+            .returning(|_, _| ready(Ok(())));
+
+        assert_eq!(dispatcher(repository).dispatch_due().await.unwrap(), 2);
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+}
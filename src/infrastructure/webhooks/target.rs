@@ -0,0 +1,175 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+};
+
+use anyhow::Result;
+use reqwest::{
+    Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect::Policy,
+};
+
+// Webhooks are registered by any brawler, so the server must never be pointed at itself, the
+// internal network or the cloud metadata endpoint
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [first, second, ..] = v4.octets();
+            let this_network = first == 0;
+            let shared = first == 100 && (second & 0xc0) == 64;
+            let benchmarking = first == 198 && (second & 0xfe) == 18;
+            let reserved = first >= 240;
+
+            !(v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_multicast()
+                || this_network
+                || shared
+                || benchmarking
+                || reserved)
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = embedded_ipv4(v6) {
+                return is_public(IpAddr::V4(v4));
+            }
+            let first = v6.segments()[0];
+            let unique_local = (first & 0xfe00) == 0xfc00;
+            let link_local = (first & 0xffc0) == 0xfe80;
+
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                || unique_local
+                || link_local)
+        }
+    }
+}
+
+// IPv6 forms that route to an IPv4 address: IPv4-mapped ::ffff:a.b.c.d, IPv4-compatible
+// ::a.b.c.d, NAT64 64:ff9b::/96 and 6to4 2002::/16
+fn embedded_ipv4(v6: Ipv6Addr) -> Option<Ipv4Addr> {
+    if let Some(v4) = v6.to_ipv4_mapped() {
+        return Some(v4);
+    }
+
+    let join = |high: u16, low: u16| Ipv4Addr::from((u32::from(high) << 16) | u32::from(low));
+    match v6.segments() {
+        [0, 0, 0, 0, 0, 0, high, low] if !v6.is_loopback() && !v6.is_unspecified() => {
+            Some(join(high, low))
+        }
+        [0x64, 0xff9b, 0, 0, 0, 0, high, low] => Some(join(high, low)),
+        [0x2002, high, low, ..] => Some(join(high, low)),
+        _ => None,
+    }
+}
+
+async fn resolve_public(host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+    let addrs = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| anyhow::anyhow!("Webhook host {} does not resolve", host))?
+        .collect::<Vec<_>>();
+
+    if addrs.is_empty() {
+        return Err(anyhow::anyhow!("Webhook host {} does not resolve", host));
+    }
+    if addrs.iter().any(|addr| !is_public(addr.ip())) {
+        return Err(anyhow::anyhow!(
+            "Webhook URL must point to a public address"
+        ));
+    }
+
+    Ok(addrs)
+}
+
+// Checked when a webhook is registered and again before every delivery, since DNS can change
+pub async fn ensure_public_target(url: &str) -> Result<()> {
+    let url = Url::parse(url).map_err(|_| anyhow::anyhow!("Webhook URL is not valid"))?;
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow::anyhow!("Webhook URL is not valid"))?;
+
+    // IP literals never go through the resolver
+    match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) if is_public(ip) => Ok(()),
+        Ok(_) => Err(anyhow::anyhow!(
+            "Webhook URL must point to a public address"
+        )),
+        Err(_) => resolve_public(host, port).await.map(|_| ()),
+    }
+}
+
+// Resolves again at connect time, so a name that flips to a private address between the check
+// and the request is still refused
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = resolve_public(name.as_str(), 0).await?;
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+// Redirects are not followed: a public receiver could otherwise bounce the request inward
+pub fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .redirect(Policy::none())
+        .dns_resolver(Arc::new(PublicResolver))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_public_addresses_are_rejected() {
+        let cases = [
+            ("0.0.0.0", false),
+            ("0.1.2.3", false),
+            ("10.0.0.1", false),
+            ("100.64.0.1", false),
+            ("127.0.0.1", false),
+            ("169.254.169.254", false),
+            ("172.16.0.1", false),
+            ("192.168.1.1", false),
+            ("198.18.0.1", false),
+            ("198.19.255.255", false),
+            ("224.0.0.1", false),
+            ("240.0.0.1", false),
+            ("255.255.255.255", false),
+            ("::", false),
+            ("::1", false),
+            ("fc00::1", false),
+            ("fe80::1", false),
+            ("ff02::1", false),
+            ("::ffff:127.0.0.1", false),
+            ("::127.0.0.1", false),
+            ("::10.0.0.1", false),
+            ("64:ff9b::a9fe:a9fe", false),
+            ("64:ff9b::c0a8:101", false),
+            ("2002:7f00:1::", false),
+            ("2002:a00:1::1", false),
+            ("8.8.8.8", true),
+            ("100.128.0.1", true),
+            ("198.20.0.1", true),
+            ("2606:4700::1111", true),
+            ("::ffff:8.8.8.8", true),
+            ("::8.8.8.8", true),
+            ("64:ff9b::808:808", true),
+            ("2002:808:808::", true),
+        ];
+
+        for (ip, expected) in cases {
+            assert_eq!(is_public(ip.parse().unwrap()), expected, "{}", ip);
+        }
+    }
+}
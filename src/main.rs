@@ -2,26 +2,31 @@ use std::sync::Arc;
 
 
 
-use server::{config::config_loader, infrastructure::{database::postgresql_connection, http::http_serv::start}};
+use server::{config::config_loader, infrastructure::{database::{migrator, postgresql_connection::{self, PgPoolRunner}}, http::http_serv::start, telemetry}};
 use tracing::{error, info};
 
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::DEBUG)
-        .init();
+    // Logging is configured by the config itself, so a config error can only go to stderr
+    let config = match config_loader::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load config: {}", e);
+            std::process::exit(1);
+        }
+    };
 
-    let dotenvy_env = match config_loader::load() {
-        Ok(env) => env,
+    let telemetry = match telemetry::init(&config.telemetry) {
+        Ok(guard) => guard,
         Err(e) => {
-            error!("Failed to load ENV: {}", e);
+            eprintln!("Failed to set up logging: {}", e);
             std::process::exit(1);
         }
     };
 
-    info!(".ENV LOADED");
+    info!("Config loaded for stage {}", config.stage);
 
-    let postgres_pool = match postgresql_connection::establish_connection(&dotenvy_env.database.url)
+    let postgres_pool = match postgresql_connection::establish_connection(&config.database)
     {
         Ok(pool) => pool,
         Err(err) => {
@@ -31,7 +36,37 @@ async fn main() {
     };
     info!("Connected DB");
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool))
-        .await
-        .expect("Failed to start server");
+    // `server --migrate` applies pending migrations and exits, for deploys that migrate as a separate step
+    let migrate_only = std::env::args().any(|arg| arg == "--migrate");
+
+    if migrate_only || config.database.auto_migrate {
+        match postgres_pool.run(migrator::run_pending).await {
+            Ok(applied) => info!("Applied {} migration(s) {:?}", applied.len(), applied),
+            Err(err) => {
+                error!("{}", err);
+                std::process::exit(1)
+            }
+        }
+    }
+
+    if migrate_only {
+        return;
+    }
+
+    if let Err(err) = postgres_pool.run(migrator::verify).await {
+        error!("Refusing to start: {}", err);
+        std::process::exit(1)
+    }
+
+    let served = start(Arc::new(config), Arc::new(postgres_pool)).await;
+    telemetry.shutdown();
+
+    if let Err(err) = served {
+        error!("Server stopped: {}", err);
+        std::process::exit(1)
+    }
+
+    // Exiting here instead of returning keeps the runtime from waiting on queries that were
+    // abandoned when the drain timeout elapsed
+    std::process::exit(0)
 }
\ No newline at end of file